@@ -0,0 +1,92 @@
+//! Example on the ways options can be provided: attribute arguments, short attribute forms &
+//! custom value syntax
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example attribute_forms`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use macroific::prelude::*;
+    use proc_macro2::{Span, TokenStream};
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[derive(AttributeOptions, Debug)]
+    struct Args {
+        #[attr_opts(default = false)]
+        path: String,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    struct Short {
+        #[attr_opts(from_name_value)]
+        rename: Option<String>,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    struct ListOnly {
+        skip: bool,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    #[attr_opts(value_syntax(colon, brace))]
+    struct Colon {
+        name: String,
+    }
+
+    #[test]
+    fn missing_args() {
+        let opts = Args::from_args(quote!(path = "/users")).unwrap();
+        assert_eq!(opts.path, "/users");
+
+        let err = Args::from_args(TokenStream::new()).unwrap_err();
+        assert_eq!(err.to_string(), "Missing required attribute: path");
+    }
+
+    #[test]
+    fn name_value_meta() {
+        let meta: syn::Meta = parse_quote!(route = "/users");
+        let err = Args::from_meta(&meta).unwrap_err();
+        assert_eq!(err.to_string(), "Expected a list, e.g. `route(...)`");
+    }
+
+    #[test]
+    fn duplicate_short_form() {
+        let opts = Short::from_attr(parse_quote!(#[my = "foo"])).unwrap();
+        assert_eq!(opts.rename.as_deref(), Some("foo"));
+
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote!(#[my = "foo"]),
+            parse_quote!(#[my(rename = "bar")]),
+        ];
+        let err = Short::from_iter_named("my", Span::call_site(), attrs).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate attribute");
+    }
+
+    #[test]
+    fn list_only() {
+        assert!(!ListOnly::from_attr(parse_quote!(#[my])).unwrap().skip);
+
+        let err = ListOnly::from_attr(parse_quote!(#[my = "foo"])).unwrap_err();
+        assert_eq!(err.to_string(), "Expected a list, e.g. `my(...)`");
+    }
+
+    #[test]
+    fn unsupported_value_syntax() {
+        assert_eq!(
+            Colon::from_args(quote!(name: "users")).unwrap().name,
+            "users"
+        );
+        assert_eq!(
+            Colon::from_args(quote!(name { "users" })).unwrap().name,
+            "users"
+        );
+
+        let err = Colon::from_args(quote!(name = "users")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unsupported value syntax `= value`, expected `: value` or `{ value }`"
+        );
+    }
+}
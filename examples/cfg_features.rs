@@ -0,0 +1,71 @@
+//! Example on evaluating `#[cfg_attr(...)]` predicates
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example cfg_features`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use macroific::attr_parse::CfgFeatures;
+    use syn::{parse_quote, Attribute};
+
+    #[test]
+    fn cfg_options() {
+        let features = CfgFeatures::new(["serde"])
+            .with_cfg("unix", None)
+            .with_cfg("target_os", Some("linux"));
+
+        let attr: Attribute =
+            parse_quote!(#[cfg_attr(all(unix, target_os = "linux", feature = "serde"), my(x))]);
+        let mut matching = Vec::new();
+        assert!(features
+            .collect_matching(attr, "my", &mut matching)
+            .unwrap()
+            .is_none());
+        assert_eq!(matching.len(), 1);
+
+        assert!(!features.eval(&parse_quote!(windows)).unwrap());
+        assert!(!features.eval(&parse_quote!(target_os = "macos")).unwrap());
+    }
+
+    #[test]
+    fn unknown_predicates_are_false() {
+        let features = CfgFeatures::default();
+        assert!(!features.eval(&parse_quote!(version("1.80"))).unwrap());
+        assert!(!features.eval(&parse_quote!(a::b)).unwrap());
+        assert!(features.eval(&parse_quote!(not(unix))).unwrap());
+
+        let attr: Attribute = parse_quote!(#[cfg_attr(unix, my(x))]);
+        let rest = features.collect_matching(attr, "my", &mut Vec::new());
+        assert!(rest.unwrap().is_some());
+    }
+
+    #[test]
+    fn from_env() {
+        std::env::set_var("CARGO_CFG_MACROIFIC_EXAMPLE_FLAG", "");
+        std::env::set_var("CARGO_CFG_MACROIFIC_EXAMPLE_LIST", "a,b");
+        std::env::set_var("CARGO_FEATURE_MACROIFIC_EXAMPLE", "1");
+
+        let features = CfgFeatures::from_env();
+        assert!(features.is_set("macroific_example_flag", None));
+        assert!(features.is_set("macroific_example_list", Some("a")));
+        assert!(features.is_set("macroific_example_list", Some("b")));
+        assert!(!features.is_set("macroific_example_list", None));
+        assert!(features.is_enabled("macroific-example"));
+    }
+
+    #[test]
+    fn not_arity() {
+        let err = CfgFeatures::default()
+            .eval(&parse_quote!(not(feature = "a", feature = "b")))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "`not` takes exactly one predicate");
+    }
+
+    #[test]
+    fn normalised_names() {
+        let features = CfgFeatures::new(["Serde-JSON"]);
+        assert!(features.is_enabled("serde_json"));
+        assert!(!features.is_enabled("serde"));
+    }
+}
@@ -0,0 +1,64 @@
+//! Example on sequence & map options and the errors around them
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example collections`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use std::collections::{BTreeSet, HashMap};
+
+    use macroific::attr_parse::OrderedMap;
+    use macroific::prelude::*;
+    use syn::parse_quote;
+
+    #[derive(AttributeOptions, Debug)]
+    struct Options {
+        tags: Option<BTreeSet<u8>>,
+        rgb: Option<[u8; 3]>,
+        limits: Option<HashMap<String, u8>>,
+        headers: Option<OrderedMap<String, u8>>,
+    }
+
+    fn error(attr: syn::Attribute) -> String {
+        Options::from_attr(attr).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn sequences() {
+        let opts = Options::from_attr(parse_quote!(#[my(tags(2, 1), rgb = [1, 2, 3])])).unwrap();
+        assert_eq!(opts.tags, Some(BTreeSet::from([1, 2])));
+        assert_eq!(opts.rgb, Some([1, 2, 3]));
+    }
+
+    #[test]
+    fn maps() {
+        let opts =
+            Options::from_attr(parse_quote!(#[my(limits(a = 1), headers(b = 1, "x-a" = 2))]))
+                .unwrap();
+        assert_eq!(opts.limits, Some(HashMap::from([("a".to_owned(), 1)])));
+        assert_eq!(
+            opts.headers.unwrap().into_inner(),
+            [("b".to_owned(), 1), ("x-a".to_owned(), 2)]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(error(parse_quote!(#[my(tags(1, 1))])), "Duplicate element");
+        assert_eq!(
+            error(parse_quote!(#[my(rgb(1, 2))])),
+            "Expected 3 elements, found 2"
+        );
+
+        // String keys compare by value: `a`, `"a"` & `r"a"` are the same key
+        assert_eq!(
+            error(parse_quote!(#[my(limits(a = 1, r"a" = 2))])),
+            "Duplicate key"
+        );
+        assert_eq!(
+            error(parse_quote!(#[my(headers(b = 1, a = 2, "b" = 3))])),
+            "Duplicate key"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example derive_input_with_opts`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use macroific::attr_parse::{DataWithOpts, DeriveInputWithOpts};
+    use macroific::prelude::*;
+
+    #[derive(AttributeOptions)]
+    struct ContainerOptions {
+        rename_all: Option<String>,
+    }
+
+    #[derive(AttributeOptions)]
+    struct VariantOptions {
+        skip: bool,
+    }
+
+    #[derive(AttributeOptions)]
+    struct FieldOptions {
+        #[attr_opts(default = false)]
+        name: syn::LitStr,
+    }
+
+    type Input = DeriveInputWithOpts<ContainerOptions, FieldOptions, VariantOptions>;
+
+    fn error(input: syn::DeriveInput) -> syn::Error {
+        Input::from_attr_name(input, "my_attr").err().unwrap()
+    }
+
+    #[test]
+    fn main() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[my_attr(rename_all = "camelCase")]
+            pub enum Foo {
+                A {
+                    #[my_attr(name = "a")]
+                    a: u8,
+                },
+                #[my_attr(skip)]
+                B,
+            }
+        };
+
+        let input = Input::from_attr_name(input, "my_attr").expect("Error parsing input");
+        assert_eq!(input.ident, "Foo");
+        assert_eq!(input.options.rename_all.unwrap(), "camelCase");
+
+        let data = match input.data {
+            DataWithOpts::Enum(data) => data,
+            DataWithOpts::Struct(_) => panic!("Expected an enum"),
+        };
+        let variants = data.variants.into_iter().collect::<Vec<_>>();
+        assert_eq!(variants.len(), 2);
+
+        assert!(!variants[0].options.skip);
+        let names = variants[0]
+            .fields
+            .iter()
+            .map(|field| field.options.name.value())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["a"]);
+
+        assert!(variants[1].options.skip);
+        assert_eq!(variants[1].fields.iter().count(), 0);
+    }
+
+    #[test]
+    fn errors_are_combined() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[my_attr(rename_all = 1)]
+            struct Foo {
+                a: u8,
+                #[my_attr(name = "b")]
+                b: u8,
+                c: u8,
+            }
+        };
+
+        let messages = error(input)
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(messages.len(), 3);
+        assert_eq!(&messages[1..], ["Missing required attribute: name"; 2]);
+    }
+
+    #[test]
+    fn unions() {
+        let input: syn::DeriveInput = syn::parse_quote!(union Foo { a: u8 });
+
+        assert_eq!(error(input).to_string(), "Unions not supported");
+    }
+
+    #[test]
+    fn discriminants_and_generics() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[my_attr(rename_all = "snake_case")]
+            #[cfg_attr(unix, my_attr(rename_all = "unix"))]
+            #[repr(u8)]
+            enum Foo<'a, T: Clone>
+            where
+                T: Default,
+            {
+                #[my_attr(skip)]
+                A = 1,
+                B(#[my_attr(name = "b")] &'a T) = 2,
+            }
+        };
+
+        let input = Input::from_attr_name(input, "my_attr").expect("Error parsing input");
+        assert_eq!(input.options.rename_all.unwrap(), "snake_case");
+        assert_eq!(input.attrs.len(), 2);
+
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+        assert_eq!(
+            quote::quote!(#impl_generics #ty_generics #where_clause).to_string(),
+            "< 'a , T : Clone > < 'a , T > where T : Default ,"
+        );
+
+        let DataWithOpts::Enum(data) = input.data else {
+            panic!("Expected an enum");
+        };
+        let variants = data
+            .variants
+            .iter()
+            .map(|variant| {
+                let (_, discriminant) = variant.discriminant.as_ref().unwrap();
+                let discriminant = quote::ToTokens::to_token_stream(discriminant).to_string();
+                let names = variant.fields.iter().map(|f| f.options.name.value());
+                (
+                    variant.options.skip,
+                    discriminant,
+                    names.collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            variants,
+            [
+                (true, "1".into(), vec![]),
+                (false, "2".into(), vec!["b".to_string()])
+            ]
+        );
+    }
+}
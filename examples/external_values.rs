@@ -0,0 +1,86 @@
+//! Example on options read from environment variables & files while the macro runs
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example external_values`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use macroific::attr_parse::{EnvValue, FileContents};
+    use macroific::prelude::*;
+    use syn::parse_quote;
+
+    #[derive(ParseOption, Debug, PartialEq)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    struct Options {
+        name: Option<EnvValue>,
+        major: Option<EnvValue<u16>>,
+        ty: Option<EnvValue<syn::Type>>,
+        server: Option<EnvValue<Server>>,
+        manifest: Option<FileContents>,
+    }
+
+    fn error(attr: syn::Attribute) -> String {
+        Options::from_attr(attr).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn env_values() {
+        std::env::set_var(
+            "MACROIFIC_EXAMPLE_SERVER",
+            r#"host = "localhost", port = 80"#,
+        );
+        let opts = Options::from_attr(parse_quote!(#[my(
+            name = env("CARGO_PKG_NAME"),
+            major = env("CARGO_PKG_VERSION_MAJOR"),
+            ty = env("CARGO_PKG_NAME"),
+            server = env("MACROIFIC_EXAMPLE_SERVER"),
+        )]))
+        .unwrap();
+
+        assert_eq!(*opts.name.unwrap(), "macroific");
+        assert_eq!(
+            Some(*opts.major.unwrap()),
+            env!("CARGO_PKG_VERSION_MAJOR").parse().ok()
+        );
+        assert_eq!(*opts.ty.unwrap(), parse_quote!(macroific));
+        assert_eq!(
+            opts.server.unwrap().into_inner(),
+            Server {
+                host: "localhost".into(),
+                port: 80,
+            }
+        );
+    }
+
+    #[test]
+    fn env_parse_error() {
+        assert!(error(parse_quote!(#[my(major = env("CARGO_PKG_NAME"))]))
+            .starts_with("Failed to parse environment variable `CARGO_PKG_NAME`: "));
+    }
+
+    #[test]
+    fn file_contents() {
+        let opts = Options::from_attr(parse_quote!(#[my(manifest = file("Cargo.toml"))])).unwrap();
+        let manifest = opts.manifest.unwrap();
+
+        assert!(manifest.contains("[package]"));
+        assert!(manifest.path().is_absolute());
+    }
+
+    #[test]
+    fn file_errors() {
+        assert!(error(parse_quote!(#[my(manifest = file("nope.sql"))]))
+            .starts_with("Failed to read `nope.sql`"));
+
+        let err = FileContents::<u32>::from_expr(parse_quote!(file("Cargo.toml"))).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Failed to parse `Cargo.toml`: "));
+    }
+}
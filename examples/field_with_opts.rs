@@ -4,8 +4,9 @@ fn main() {
 
 #[cfg(all(test, feature = "attr_parse"))]
 mod test {
-    use macroific::attr_parse::FieldsWithOpts;
+    use macroific::attr_parse::{CfgFeatures, FieldWithOpts, FieldsWithOpts};
     use macroific::prelude::*;
+    use syn::Attribute;
 
     #[derive(AttributeOptions)]
     struct Options {
@@ -13,6 +14,13 @@ mod test {
         bar: syn::LitStr,
     }
 
+    #[derive(AttributeOptions, Debug)]
+    struct Forwarding {
+        #[attr_opts(forward_attrs(allow))]
+        forwarded: Vec<Attribute>,
+        skip: bool,
+    }
+
     #[test]
     fn main() {
         let input: syn::DeriveInput = syn::parse_quote! {
@@ -41,4 +49,112 @@ mod test {
         assert_eq!(&*first_field.options.bar.value(), "qux");
         assert_eq!(first_field.field.ident.unwrap(), "baz");
     }
+
+    #[test]
+    fn cfg_attr() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct Foo {
+                #[cfg_attr(feature = "qux", my_attr(bar = "qux"), doc = "Qux")]
+                #[cfg_attr(not(feature = "qux"), my_attr(bar = "not qux"))]
+                baz: &'static str,
+            }
+        };
+        let data = input.data.extract_struct().unwrap();
+        let features = CfgFeatures::new(["qux"]);
+
+        let fields =
+            FieldsWithOpts::<Options>::from_attr_name_cfg(data.fields, "my_attr", &features)
+                .expect("Error parsing fields");
+
+        let FieldsWithOpts::Named { fields, .. } = fields else {
+            panic!("Expected named fields");
+        };
+        let field = fields.into_iter().next().unwrap();
+
+        assert_eq!(&*field.options.bar.value(), "qux");
+
+        // The active `cfg_attr` keeps its other attributes, the inactive one is left as is
+        let attrs = field
+            .field
+            .attrs
+            .iter()
+            .map(|attr| quote::ToTokens::to_token_stream(&attr.meta).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attrs,
+            [
+                "cfg_attr (feature = \"qux\" , doc = \"Qux\")",
+                "cfg_attr (not (feature = \"qux\") , my_attr (bar = \"not qux\"))",
+            ]
+        );
+    }
+
+    #[test]
+    fn cfg_attr_untouched_by_default() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct Foo {
+                #[my_attr(bar = "qux")]
+                #[cfg_attr(unix, my_attr(bar = "unix"))]
+                baz: &'static str,
+            }
+        };
+        let data = input.data.extract_struct().unwrap();
+
+        let fields = FieldsWithOpts::<Options>::from_attr_name(data.fields, "my_attr")
+            .expect("Error parsing fields");
+        let field = fields.iter().next().unwrap();
+
+        assert_eq!(&*field.options.bar.value(), "qux");
+        assert_eq!(field.field.attrs.len(), 1);
+        assert!(field.field.attrs[0].path().is_ident("cfg_attr"));
+    }
+
+    #[test]
+    fn owned_and_namespaced_names() {
+        let field: syn::Field = syn::parse_quote! {
+            #[myorg::my_attr(bar = "qux")]
+            baz: u8
+        };
+        let field = FieldWithOpts::<Options>::from_attr_path(field, ["myorg", "my_attr"])
+            .expect("Error parsing field");
+        assert_eq!(&*field.options.bar.value(), "qux");
+
+        let attr_name = String::from("my_attr");
+        let field: syn::Field = syn::parse_quote!(#[my_attr(bar = "qux")] baz: u8);
+        let field = FieldWithOpts::<Options>::from_attr_name(field, &attr_name)
+            .expect("Error parsing field");
+        assert_eq!(&*field.options.bar.value(), "qux");
+    }
+
+    #[test]
+    fn from_predicates() {
+        let field: syn::Field = syn::parse_quote! {
+            #[my_attr(bar = "qux")]
+            #[allow(dead_code)]
+            #[serde(rename = "x")]
+            baz: u8
+        };
+
+        let (field, collected) = FieldWithOpts::<Options>::from_predicates(
+            field,
+            |attr| attr.path().is_ident("my_attr"),
+            |attr| attr.path().is_ident("allow") || attr.path().is_ident("my_attr"),
+        )
+        .expect("Error parsing field");
+
+        assert_eq!(&*field.options.bar.value(), "qux");
+        assert_eq!(collected.len(), 1);
+        assert!(collected[0].path().is_ident("allow"));
+        assert_eq!(field.field.attrs.len(), 1);
+        assert!(field.field.attrs[0].path().is_ident("serde"));
+    }
+
+    #[test]
+    fn forwarded_isnt_an_option() {
+        let err = Forwarding::from_attr(syn::parse_quote!(#[my(forwarded)])).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognised attribute: `forwarded`");
+
+        let opts = Forwarding::from_attr(syn::parse_quote!(#[my(skip)])).unwrap();
+        assert!(opts.skip && opts.forwarded.is_empty());
+    }
 }
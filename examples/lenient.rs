@@ -0,0 +1,92 @@
+//! Example on `#[attr_opts(lenient)]` carrying through wrapper types
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example lenient`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use std::collections::BTreeMap;
+
+    use macroific::attr_parse::Either;
+    use macroific::prelude::*;
+    use syn::parse_quote;
+
+    #[derive(AttributeOptions)]
+    #[attr_opts(lenient)]
+    struct Lenient {
+        ports: Option<Vec<u16>>,
+        limits: Option<BTreeMap<String, u8>>,
+        id: Option<Either<u8, bool>>,
+        rename: Option<syn::Ident>,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    struct Strict {
+        ports: Option<Vec<u16>>,
+        rename: Option<syn::Ident>,
+        #[attr_opts(lenient)]
+        name: Option<String>,
+    }
+
+    fn strict_error(attr: syn::Attribute) -> String {
+        Strict::from_attr(attr).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn sequence() {
+        let opts = Lenient::from_attr(parse_quote!(#[my(ports = ["80", 443])])).unwrap();
+        assert_eq!(opts.ports, Some(vec![80, 443]));
+
+        let opts = Lenient::from_attr(parse_quote!(#[my(ports("80", "443"))])).unwrap();
+        assert_eq!(opts.ports, Some(vec![80, 443]));
+
+        let opts = Strict::from_attr(parse_quote!(#[my(ports = [80])])).unwrap();
+        assert_eq!(opts.ports, Some(vec![80]));
+        assert_eq!(
+            strict_error(parse_quote!(#[my(ports = ["80"])])),
+            "Incompatible literal"
+        );
+    }
+
+    #[test]
+    fn map() {
+        let opts = Lenient::from_attr(parse_quote!(#[my(limits(a = "1", b = 2))])).unwrap();
+        let expected = BTreeMap::from([("a".into(), 1), ("b".into(), 2)]);
+        assert_eq!(opts.limits, Some(expected));
+    }
+
+    #[test]
+    fn either() {
+        let opts = Lenient::from_attr(parse_quote!(#[my(id = "3")])).unwrap();
+        assert_eq!(opts.id.and_then(Either::left), Some(3));
+
+        let opts = Lenient::from_attr(parse_quote!(#[my(id = "true")])).unwrap();
+        assert_eq!(opts.id.and_then(Either::right), Some(true));
+    }
+
+    #[test]
+    fn ident() {
+        let opts = Lenient::from_attr(parse_quote!(#[my(rename = type)])).unwrap();
+        assert_eq!(opts.rename.unwrap(), "type");
+
+        let opts = Lenient::from_attr(parse_quote!(#[my(rename = true)])).unwrap();
+        assert_eq!(opts.rename.unwrap(), "true");
+
+        assert!(Strict::from_attr(parse_quote!(#[my(rename = type)])).is_err());
+    }
+
+    #[test]
+    fn field_level() {
+        let opts = Strict::from_attr(parse_quote!(#[my(name = 5)])).unwrap();
+        assert_eq!(opts.name.as_deref(), Some("5"));
+
+        let opts = Strict::from_attr(parse_quote!(#[my(name = 5, rename = r#type)])).unwrap();
+        assert_eq!(opts.rename.unwrap(), "r#type");
+
+        assert_eq!(
+            strict_error(parse_quote!(#[my(ports = [80], name = 5, rename = 5)])),
+            "expected identifier"
+        );
+    }
+}
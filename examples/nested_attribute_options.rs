@@ -0,0 +1,57 @@
+//! Example on nesting an [`AttributeOptions`](::macroific::attr_parse::AttributeOptions) struct
+//! with `#[attr_opts(parse_option)]` inside another
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example nested_attribute_options`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use macroific::prelude::*;
+    use syn::parse_quote;
+
+    #[derive(AttributeOptions, Debug, Eq, PartialEq)]
+    #[attr_opts(parse_option)]
+    struct Route {
+        path: Option<String>,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    struct Api {
+        route: Option<Route>,
+        version: Option<u8>,
+    }
+
+    fn error(attr: syn::Attribute) -> String {
+        Api::from_attr(attr).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn bare_key() {
+        let opts = Api::from_attr(parse_quote!(#[api(route, version = 1)])).unwrap();
+        assert_eq!(opts.route, Some(Route { path: None }));
+        assert_eq!(opts.version, Some(1));
+    }
+
+    #[test]
+    fn trailing_tokens() {
+        assert_eq!(
+            error(parse_quote!(#[api(route path = "/")])),
+            "Expected `= value`, `(...)` or `,` after the key"
+        );
+    }
+
+    #[test]
+    fn duplicate_dotted_key() {
+        assert_eq!(
+            error(parse_quote!(#[api(route(path = "/"), route.path = "/v2")])),
+            "duplicate attribute"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let route: Route = parse_quote!(path = "/");
+        assert_eq!(route.path.as_deref(), Some("/"));
+    }
+}
@@ -0,0 +1,71 @@
+//! Example on number options: constant arithmetic, wrapping & the errors around them
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example numbers`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use std::num::{NonZeroU32, Wrapping};
+
+    use macroific::prelude::*;
+    use syn::parse_quote;
+
+    #[derive(AttributeOptions, Debug)]
+    struct Options {
+        byte: Option<u8>,
+        non_zero: Option<NonZeroU32>,
+        wrapping: Option<Wrapping<u8>>,
+        signed: Option<Wrapping<i8>>,
+    }
+
+    fn error(attr: syn::Attribute) -> String {
+        Options::from_attr(attr).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let opts =
+            Options::from_attr(parse_quote!(#[my(byte = 2 * 3, non_zero = 1 << 4)])).unwrap();
+        assert_eq!(opts.byte, Some(6));
+        assert_eq!(opts.non_zero, NonZeroU32::new(16));
+    }
+
+    #[test]
+    fn wrapping() {
+        let opts =
+            Options::from_attr(parse_quote!(#[my(wrapping = 255 + 1, signed = -128 - 1)])).unwrap();
+        assert_eq!(opts.wrapping, Some(Wrapping(0)));
+        assert_eq!(opts.signed, Some(Wrapping(127)));
+
+        let opts = Options::from_attr(parse_quote!(#[my(wrapping = -1)])).unwrap();
+        assert_eq!(opts.wrapping, Some(Wrapping(255)));
+
+        let opts = Options::from_attr(parse_quote!(#[my(wrapping = 1 << 9)])).unwrap();
+        assert_eq!(opts.wrapping, Some(Wrapping(2)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            error(parse_quote!(#[my(byte = 255 + 1)])),
+            "Arithmetic overflow"
+        );
+        assert_eq!(
+            error(parse_quote!(#[my(byte = 5u16)])),
+            "Expected a `u8` literal, found a `u16` suffix"
+        );
+        assert_eq!(
+            error(parse_quote!(#[my(non_zero = 0)])),
+            "Value can't be zero"
+        );
+        assert_eq!(
+            error(parse_quote!(#[my(wrapping = 1 / 0)])),
+            "Division by zero"
+        );
+        assert_eq!(
+            error(parse_quote!(#[my(wrapping = 256)])),
+            "Literal out of range for `u8`"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Example on `#[attr_opts(positional)]` fields & the errors around them
+
+fn main() {
+    println!("Run me with `cargo test --features attr_parse --example positional`");
+}
+
+#[cfg(all(test, feature = "attr_parse"))]
+mod test {
+    use macroific::prelude::*;
+    use syn::parse_quote;
+
+    #[derive(ParseOption, Debug, Default)]
+    struct Limit {
+        #[attr_opts(positional)]
+        count: u32,
+        #[attr_opts(positional, default)]
+        per: Option<syn::Ident>,
+    }
+
+    #[derive(AttributeOptions, Debug)]
+    struct Route {
+        #[attr_opts(positional)]
+        path: String,
+        guard: Option<syn::Ident>,
+        limit: Option<Limit>,
+    }
+
+    #[derive(AttributeOptions)]
+    struct Check {
+        #[attr_opts(positional)]
+        expr: syn::Expr,
+        per: Option<u32>,
+    }
+
+    fn error(attr: syn::Attribute) -> String {
+        Route::from_attr(attr).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn positional_then_keyed() {
+        let route =
+            Route::from_attr(parse_quote!(#[get("/", guard = auth, limit(10, minute))])).unwrap();
+        assert_eq!(route.path, "/");
+        assert_eq!(route.guard.unwrap(), "auth");
+
+        let limit = route.limit.unwrap();
+        assert_eq!(limit.count, 10);
+        assert_eq!(limit.per.unwrap(), "minute");
+    }
+
+    #[test]
+    fn missing() {
+        assert_eq!(
+            error(parse_quote!(#[get(guard = auth)])),
+            "Missing positional argument: path"
+        );
+        assert_eq!(
+            error(parse_quote!(#[get("/", limit(per = minute))])),
+            "Missing positional argument: count"
+        );
+    }
+
+    #[test]
+    fn too_many() {
+        assert_eq!(
+            error(parse_quote!(#[get("/", "/users")])),
+            "Too many positional arguments, expected 1"
+        );
+        assert_eq!(
+            error(parse_quote!(#[get("/", limit(10, minute, 5))])),
+            "Too many positional arguments, expected at most 2"
+        );
+    }
+
+    #[test]
+    fn after_keyed() {
+        assert_eq!(
+            error(parse_quote!(#[get(guard = auth, "/")])),
+            "Positional arguments must come before keyed options"
+        );
+        assert_eq!(
+            error(parse_quote!(#[get("/", limit(per = minute, 10))])),
+            "Positional arguments must come before keyed options"
+        );
+    }
+
+    #[test]
+    fn label_is_keyed() {
+        // The leading `guard` is the bare `guard` option, not the path
+        assert_eq!(
+            error(parse_quote!(#[get(guard, guard = auth)])),
+            "expected identifier"
+        );
+    }
+
+    #[test]
+    fn expressions_starting_with_a_label() {
+        let expr = |attr: syn::Attribute| Check::from_attr(attr).unwrap().expr;
+        assert_eq!(expr(parse_quote!(#[check(per * 2)])), parse_quote!(per * 2));
+        assert_eq!(
+            expr(parse_quote!(#[check(per::MAX)])),
+            parse_quote!(per::MAX)
+        );
+        assert_eq!(
+            expr(parse_quote!(#[check(anything(1))])),
+            parse_quote!(anything(1))
+        );
+
+        let check = Check::from_attr(parse_quote!(#[check(per == 2, per = 1)])).unwrap();
+        assert_eq!(check.expr, parse_quote!(per == 2));
+        assert_eq!(check.per, Some(1));
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "module-prefix")]
+use macroific_core::elements::ModulePrefix;
+use syn::{Path, PathArguments};
+
+/// An attribute name to match paths against: `"my"` matches `#[my(...)]` and `["myorg", "serde"]`
+/// matches tool-style `#[myorg::serde(...)]` attributes. The `module-prefix` feature implements it
+/// for `ModulePrefix` too, which also matches `#[::myorg::serde(...)]`.
+///
+/// # Example
+///
+/// ```
+/// # use macroific_attr_parse::AttrName;
+/// # use syn::{parse_quote, Path};
+/// #
+/// let path: Path = parse_quote!(myorg::serde);
+/// assert!(["myorg", "serde"].matches_path(&path));
+/// assert!(!"serde".matches_path(&path));
+///
+/// let path: Path = parse_quote!(serde);
+/// assert!("serde".matches_path(&path));
+/// assert!(!["myorg", "serde"].matches_path(&path));
+/// ```
+pub trait AttrName {
+    /// Whether the attribute path matches this name
+    fn matches_path(&self, path: &Path) -> bool;
+}
+
+impl AttrName for str {
+    #[inline]
+    fn matches_path(&self, path: &Path) -> bool {
+        path.is_ident(self)
+    }
+}
+
+impl AttrName for String {
+    #[inline]
+    fn matches_path(&self, path: &Path) -> bool {
+        path.is_ident(self)
+    }
+}
+
+impl AttrName for [&str] {
+    #[inline]
+    fn matches_path(&self, path: &Path) -> bool {
+        path.leading_colon.is_none() && matches_segments(self, path)
+    }
+}
+
+/// Whether the path's segments match `names`, regardless of any leading `::`
+fn matches_segments(names: &[&str], path: &Path) -> bool {
+    path.segments.len() == names.len()
+        && path.segments.iter().zip(names).all(move |(segment, name)| {
+            matches!(segment.arguments, PathArguments::None) && segment.ident == name
+        })
+}
+
+impl<const LEN: usize> AttrName for [&str; LEN] {
+    #[inline]
+    fn matches_path(&self, path: &Path) -> bool {
+        self[..].matches_path(path)
+    }
+}
+
+impl<T: AttrName + ?Sized> AttrName for &T {
+    #[inline]
+    fn matches_path(&self, path: &Path) -> bool {
+        (**self).matches_path(path)
+    }
+}
+
+/// Matches paths starting with `::` if the [leading separator](ModulePrefix::leading_sep) is
+/// enabled & ones without it otherwise.
+///
+/// ```
+/// # use macroific_attr_parse::AttrName;
+/// # use macroific_core::elements::ModulePrefix;
+/// # use syn::{parse_quote, Path};
+/// #
+/// let prefixed = ModulePrefix::new(["myorg", "serde"]);
+/// let unprefixed = prefixed.with_leading_sep(false);
+/// let absolute: Path = parse_quote!(::myorg::serde);
+/// let relative: Path = parse_quote!(myorg::serde);
+///
+/// assert!(prefixed.matches_path(&absolute));
+/// assert!(!prefixed.matches_path(&relative));
+///
+/// assert!(unprefixed.matches_path(&relative));
+/// assert!(!unprefixed.matches_path(&absolute));
+///
+/// assert!(!ModulePrefix::new(["serde"]).matches_path(&absolute));
+/// ```
+#[cfg(feature = "module-prefix")]
+impl<const LEN: usize> AttrName for ModulePrefix<'_, LEN> {
+    #[inline]
+    fn matches_path(&self, path: &Path) -> bool {
+        path.leading_colon.is_some() == self.leading_sep() && matches_segments(self, path)
+    }
+}
+
@@ -0,0 +1,280 @@
+use std::collections::BTreeSet;
+use std::env;
+
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, LitStr, Meta, MetaList, Token};
+
+use crate::__attr_parse_prelude::*;
+use crate::AttrName;
+
+/// The active features & other `cfg` options `#[cfg_attr(...)]` predicates get evaluated against
+/// when collecting attributes, e.g. in [`from_iter_named_cfg`](AttributeOptions::from_iter_named_cfg).
+/// Supports `feature = "..."`, `all(...)`, `any(...)` & `not(...)` along with options such as
+/// `unix` or `target_os = "linux"`.
+///
+/// Feature names are compared the way Cargo exposes them in `CARGO_FEATURE_*` environment
+/// variables: case-insensitively, with `-` & `_` being equivalent.
+///
+/// Options that haven't been [set](Self::with_cfg), as well as predicates it doesn't know, e.g.
+/// `version("1.80")`, evaluate to `false`, same as `rustc` treats unset options.
+///
+/// # Example
+///
+/// ```
+/// # use macroific_attr_parse::CfgFeatures;
+/// # use syn::{parse_quote, Attribute};
+/// #
+/// let features = CfgFeatures::new(["serde"]);
+/// let attr: Attribute = parse_quote!(#[cfg_attr(all(feature = "serde", not(feature = "std")), my(rename = "x"))]);
+///
+/// let mut matching = Vec::new();
+/// let rest = features.collect_matching(attr, "my", &mut matching).unwrap();
+///
+/// assert!(rest.is_none());
+/// assert_eq!(matching.len(), 1);
+/// assert!(matching[0].path().is_ident("my"));
+///
+/// let attr: Attribute = parse_quote!(#[cfg_attr(feature = "std", my(rename = "x"))]);
+/// assert!(features.collect_matching(attr, "my", &mut matching).unwrap().is_some());
+/// assert_eq!(matching.len(), 1);
+///
+/// let features = features.with_cfg("target_os", Some("linux"));
+/// assert!(features.eval(&parse_quote!(all(target_os = "linux", not(unix)))).unwrap());
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CfgFeatures {
+    features: BTreeSet<String>,
+    cfgs: BTreeSet<(String, Option<String>)>,
+}
+
+impl CfgFeatures {
+    /// Construct from the given feature names
+    pub fn new<I>(features: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Self {
+            features: features
+                .into_iter()
+                .map(move |feature| normalise(feature.as_ref()))
+                .collect(),
+            cfgs: BTreeSet::new(),
+        }
+    }
+
+    /// Set a `cfg` option: a name like `unix` if `value` is `None`, a key-value pair like
+    /// `target_os = "linux"` otherwise. Options with several values, e.g. `target_feature`, get
+    /// set once per value.
+    #[must_use]
+    pub fn with_cfg(mut self, name: impl Into<String>, value: Option<&str>) -> Self {
+        self.cfgs.insert((name.into(), value.map(String::from)));
+        self
+    }
+
+    /// Read the features from the `CARGO_FEATURE_*` environment variables & the other `cfg`
+    /// options from the `CARGO_CFG_*` ones.
+    ///
+    /// Cargo only sets these when running build scripts, so this is meant for build-time code
+    /// generators. Proc macros don't see them & should get their features from the caller
+    /// instead, e.g. as macro arguments.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let mut out = Self::default();
+        for (key, value) in env::vars() {
+            if let Some(feature) = key.strip_prefix("CARGO_FEATURE_") {
+                out.features.insert(normalise(feature));
+            } else if let Some(name) = key.strip_prefix("CARGO_CFG_") {
+                let name = name.to_ascii_lowercase();
+                if value.is_empty() {
+                    out.cfgs.insert((name, None));
+                } else {
+                    let values = value.split(',');
+                    out.cfgs
+                        .extend(values.map(|value| (name.clone(), Some(value.into()))));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Whether the feature is active
+    #[must_use]
+    pub fn is_enabled(&self, feature: &str) -> bool {
+        self.features.contains(&normalise(feature))
+    }
+
+    /// Whether the `cfg` option is set, e.g. `("unix", None)` or `("target_os", Some("linux"))`
+    #[must_use]
+    pub fn is_set(&self, name: &str, value: Option<&str>) -> bool {
+        self.cfgs
+            .contains(&(name.to_owned(), value.map(String::from)))
+    }
+
+    /// Evaluate a `cfg` predicate, e.g. `any(feature = "foo", not(unix))`. Unknown predicates
+    /// evaluate to `false`.
+    pub fn eval(&self, predicate: &Meta) -> syn::Result<bool> {
+        match *predicate {
+            Meta::NameValue(ref meta) => {
+                let value = LitStr::from_expr(meta.value.clone())?.value();
+                Ok(if meta.path.is_ident("feature") {
+                    self.is_enabled(&value)
+                } else {
+                    cfg_name(&meta.path).is_some_and(move |name| self.is_set(&name, Some(&value)))
+                })
+            }
+            Meta::Path(ref path) => {
+                Ok(cfg_name(path).is_some_and(move |name| self.is_set(&name, None)))
+            }
+            Meta::List(ref list) if list.path.is_ident("all") => {
+                for predicate in parse_predicates(list)? {
+                    if !self.eval(&predicate)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Meta::List(ref list) if list.path.is_ident("any") => {
+                for predicate in parse_predicates(list)? {
+                    if self.eval(&predicate)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Meta::List(ref list) if list.path.is_ident("not") => {
+                let mut predicates = parse_predicates(list)?.into_iter();
+                match (predicates.next(), predicates.next()) {
+                    (Some(predicate), None) => Ok(!self.eval(&predicate)?),
+                    _ => Err(Error::new_spanned(
+                        list,
+                        "`not` takes exactly one predicate",
+                    )),
+                }
+            }
+            Meta::List(_) => Ok(false),
+        }
+    }
+
+    /// Move `attr` into `out` if it matches `name`. Active `#[cfg_attr(...)]`s get unwrapped,
+    /// moving their matching attributes into `out` instead. Returns what's left of `attr`.
+    ///
+    /// Predicates only get evaluated if the `cfg_attr` contains a matching attribute.
+    pub fn collect_matching(
+        &self,
+        attr: Attribute,
+        name: impl AttrName,
+        out: &mut Vec<Attribute>,
+    ) -> syn::Result<Option<Attribute>> {
+        self.collect_matching_in(attr, &name, out)
+    }
+
+    fn collect_matching_in(
+        &self,
+        attr: Attribute,
+        name: &dyn AttrName,
+        out: &mut Vec<Attribute>,
+    ) -> syn::Result<Option<Attribute>> {
+        if !attr.path().is_ident("cfg_attr") {
+            return Ok(if name.matches_path(attr.path()) {
+                out.push(attr);
+                None
+            } else {
+                Some(attr)
+            });
+        }
+
+        let (predicate, metas) = parse_cfg_attr(&attr.meta)?;
+        if !metas.iter().any(move |meta| contains_match(meta, name)) || !self.eval(&predicate)? {
+            return Ok(Some(attr));
+        }
+
+        let mut remaining = Punctuated::<Meta, Token![,]>::new();
+        for meta in metas {
+            let inner = Attribute {
+                meta,
+                ..attr.clone()
+            };
+            if let Some(inner) = self.collect_matching_in(inner, name, out)? {
+                remaining.push(inner.meta);
+            }
+        }
+
+        Ok(if remaining.is_empty() {
+            None
+        } else {
+            Some(Attribute {
+                meta: Meta::List(MetaList {
+                    tokens: quote!(#predicate, #remaining),
+                    ..attr.meta.require_list()?.clone()
+                }),
+                ..attr
+            })
+        })
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for CfgFeatures {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+fn normalise(feature: &str) -> String {
+    feature.to_ascii_lowercase().replace('-', "_")
+}
+
+/// Name of a `cfg` option, `None` for paths that can't be one, e.g. `a::b`
+fn cfg_name(path: &syn::Path) -> Option<String> {
+    path.get_ident().map(ToString::to_string)
+}
+
+/// [`CfgFeatures::collect_matching`] if there are features to evaluate predicates against;
+/// plain path matching that leaves `cfg_attr`s alone otherwise
+pub(crate) fn collect_matching(
+    features: Option<&CfgFeatures>,
+    attr: Attribute,
+    name: &dyn AttrName,
+    out: &mut Vec<Attribute>,
+) -> syn::Result<Option<Attribute>> {
+    match features {
+        Some(features) => features.collect_matching_in(attr, name, out),
+        None if name.matches_path(attr.path()) => {
+            out.push(attr);
+            Ok(None)
+        }
+        None => Ok(Some(attr)),
+    }
+}
+
+/// Split `cfg_attr(predicate, attr1, attr2)` into the predicate & attributes
+fn parse_cfg_attr(meta: &Meta) -> syn::Result<(Meta, Vec<Meta>)> {
+    let parser = |input: ParseStream| {
+        let predicate = input.parse::<Meta>()?;
+        input.parse::<Token![,]>()?;
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+
+        Ok((predicate, metas.into_iter().collect()))
+    };
+
+    meta.require_list()?.parse_args_with(parser)
+}
+
+fn parse_predicates(list: &MetaList) -> syn::Result<Punctuated<Meta, Token![,]>> {
+    list.parse_args_with(Punctuated::parse_terminated)
+}
+
+/// Whether the meta matches `name` or is a `cfg_attr` containing a match
+fn contains_match(meta: &Meta, name: &dyn AttrName) -> bool {
+    if name.matches_path(meta.path()) {
+        return true;
+    }
+
+    meta.path().is_ident("cfg_attr")
+        && parse_cfg_attr(meta)
+            .is_ok_and(move |(_, metas)| metas.iter().any(move |meta| contains_match(meta, name)))
+}
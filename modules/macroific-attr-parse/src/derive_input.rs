@@ -0,0 +1,236 @@
+use macroific_core::extract_fields::{DataExtractExt, Rejection};
+use syn::punctuated::Punctuated;
+use syn::{
+    token, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Expr, Generics, Ident, Token,
+    Variant, Visibility,
+};
+
+use crate::errors::{collect_results, combine_results};
+use crate::field_opt::take_options_attrs;
+use crate::{AttrName, AttributeOptions, CfgFeatures, FieldsWithOpts};
+
+/// A [`DeriveInput`] with container options `C`, field options `F` & variant options `V`
+/// [parsed](AttributeOptions) from attributes of the same name. Unions aren't supported.
+///
+/// The option types are typically `#[derive(AttributeOptions)]` structs; see the `macroific`
+/// crate's `attr_parse` module for an example.
+pub struct DeriveInputWithOpts<C, F, V> {
+    /// The attributes left after taking out the container options
+    pub attrs: Vec<Attribute>,
+
+    #[allow(missing_docs)]
+    pub vis: Visibility,
+
+    #[allow(missing_docs)]
+    pub ident: Ident,
+
+    #[allow(missing_docs)]
+    pub generics: Generics,
+
+    /// The container options
+    pub options: C,
+
+    #[allow(missing_docs)]
+    pub data: DataWithOpts<F, V>,
+}
+
+/// [`Data`] with field & variant options [parsed](AttributeOptions).
+pub enum DataWithOpts<F, V> {
+    #[allow(missing_docs)]
+    Struct(StructWithOpts<F>),
+
+    #[allow(missing_docs)]
+    Enum(EnumWithOpts<F, V>),
+}
+
+/// A [`DataStruct`] with field options [parsed](AttributeOptions).
+pub struct StructWithOpts<F> {
+    #[allow(missing_docs)]
+    pub struct_token: Token![struct],
+
+    #[allow(missing_docs)]
+    pub fields: FieldsWithOpts<F>,
+
+    #[allow(missing_docs)]
+    pub semi_token: Option<Token![;]>,
+}
+
+/// A [`DataEnum`] with field & variant options [parsed](AttributeOptions).
+pub struct EnumWithOpts<F, V> {
+    #[allow(missing_docs)]
+    pub enum_token: Token![enum],
+
+    #[allow(missing_docs)]
+    pub brace_token: token::Brace,
+
+    #[allow(missing_docs)]
+    pub variants: Punctuated<VariantWithOpts<F, V>, Token![,]>,
+}
+
+/// A [`Variant`] with its own & its fields' options [parsed](AttributeOptions).
+pub struct VariantWithOpts<F, V> {
+    /// The attributes left after taking out the variant options
+    pub attrs: Vec<Attribute>,
+
+    #[allow(missing_docs)]
+    pub ident: Ident,
+
+    #[allow(missing_docs)]
+    pub fields: FieldsWithOpts<F>,
+
+    #[allow(missing_docs)]
+    pub discriminant: Option<(Token![=], Expr)>,
+
+    /// The variant options
+    pub options: V,
+}
+
+impl<C, F, V> DeriveInputWithOpts<C, F, V>
+where
+    C: AttributeOptions,
+    F: AttributeOptions,
+    V: AttributeOptions,
+{
+    /// Parse the options from attributes named `attr_name` on the container, its variants &
+    /// fields. Matching attributes get taken out; the rest are kept where they were. Errors from
+    /// all levels are combined.
+    ///
+    /// `#[cfg_attr(...)]`s are left alone; see [`from_attr_name_cfg`](Self::from_attr_name_cfg)
+    /// for evaluating them.
+    #[inline]
+    pub fn from_attr_name(input: DeriveInput, attr_name: &str) -> syn::Result<Self> {
+        Self::from_attr_path(input, attr_name)
+    }
+
+    /// [`from_attr_name`](Self::from_attr_name) for any [`AttrName`], e.g. `["myorg", "serde"]`
+    /// for `#[myorg::serde(...)]`
+    #[inline]
+    pub fn from_attr_path(input: DeriveInput, attr_name: impl AttrName) -> syn::Result<Self> {
+        Self::from_attr_name_in(input, &attr_name, None)
+    }
+
+    /// [`from_attr_path`](Self::from_attr_path) evaluating `#[cfg_attr(...)]` predicates against
+    /// the given features.
+    #[inline]
+    pub fn from_attr_name_cfg(
+        input: DeriveInput,
+        attr_name: impl AttrName,
+        features: &CfgFeatures,
+    ) -> syn::Result<Self> {
+        Self::from_attr_name_in(input, &attr_name, Some(features))
+    }
+
+    fn from_attr_name_in(
+        input: DeriveInput,
+        attr_name: &dyn AttrName,
+        features: Option<&CfgFeatures>,
+    ) -> syn::Result<Self> {
+        let DeriveInput {
+            mut attrs,
+            vis,
+            ident,
+            generics,
+            data,
+        } = input;
+
+        let options = take_options_attrs::<C>(&mut attrs, attr_name, features)
+            .and_then(|relevant_attrs| C::from_iter(ident.span(), relevant_attrs));
+        let data = DataWithOpts::from_data(data, attr_name, features);
+        let (options, data) = combine_results(options, data)?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            generics,
+            options,
+            data,
+        })
+    }
+}
+
+impl<F: AttributeOptions, V: AttributeOptions> DataWithOpts<F, V> {
+    fn from_data(
+        data: Data,
+        attr_name: &dyn AttrName,
+        features: Option<&CfgFeatures>,
+    ) -> syn::Result<Self> {
+        match data.extract_struct() {
+            Ok(DataStruct {
+                struct_token,
+                fields,
+                semi_token,
+            }) => Ok(Self::Struct(StructWithOpts {
+                struct_token,
+                fields: FieldsWithOpts::from_attr_name_in(fields, attr_name, features)?,
+                semi_token,
+            })),
+            Err(Rejection::A(DataEnum {
+                enum_token,
+                brace_token,
+                variants,
+            })) => Ok(Self::Enum(EnumWithOpts {
+                enum_token,
+                brace_token,
+                variants: collect_results(variants.into_iter().map(move |variant| {
+                    VariantWithOpts::from_variant(variant, attr_name, features)
+                }))?,
+            })),
+            Err(Rejection::B(data)) => {
+                Err(Error::new_spanned(data.union_token, "Unions not supported"))
+            }
+        }
+    }
+}
+
+impl<F, V> DataWithOpts<F, V> {
+    /// Extract the struct, erroring if this is an enum
+    pub fn into_struct(self) -> syn::Result<StructWithOpts<F>> {
+        match self {
+            Self::Struct(data) => Ok(data),
+            Self::Enum(data) => Err(Error::new_spanned(
+                data.enum_token,
+                "Only structs supported",
+            )),
+        }
+    }
+
+    /// Extract the enum, erroring if this is a struct
+    pub fn into_enum(self) -> syn::Result<EnumWithOpts<F, V>> {
+        match self {
+            Self::Enum(data) => Ok(data),
+            Self::Struct(data) => Err(Error::new_spanned(
+                data.struct_token,
+                "Only enums supported",
+            )),
+        }
+    }
+}
+
+impl<F: AttributeOptions, V: AttributeOptions> VariantWithOpts<F, V> {
+    fn from_variant(
+        variant: Variant,
+        attr_name: &dyn AttrName,
+        features: Option<&CfgFeatures>,
+    ) -> syn::Result<Self> {
+        let Variant {
+            mut attrs,
+            ident,
+            fields,
+            discriminant,
+        } = variant;
+
+        let options = take_options_attrs::<V>(&mut attrs, attr_name, features)
+            .and_then(|relevant_attrs| V::from_iter(ident.span(), relevant_attrs));
+        let fields = FieldsWithOpts::from_attr_name_in(fields, attr_name, features);
+        let (options, fields) = combine_results(options, fields)?;
+
+        Ok(Self {
+            attrs,
+            ident,
+            fields,
+            discriminant,
+            options,
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::{self, Display};
+
+use syn::{Attribute, Expr, ExprLit, Lit, Meta};
+
+/// Doc comments collected from `#[doc = "..."]` attributes, i.e. `///` & `/** */` comments. Fill
+/// it from an options struct with `#[attr_opts(docs)]` or from attributes directly.
+///
+/// Lines have their common indentation removed and leading & trailing blank lines are dropped.
+/// Docs whose value isn't a string literal, e.g. `#[doc = include_str!("README.md")]`, are
+/// skipped.
+///
+/// # Example
+///
+/// ```
+/// # use macroific_attr_parse::Docs;
+/// # use syn::{parse_quote, DeriveInput};
+/// #
+/// let input: DeriveInput = parse_quote! {
+///   ///
+///   /// Does things.
+///   ///
+///   ///     let indented = true;
+///   #[doc(hidden)]
+///   struct Foo;
+/// };
+///
+/// let docs = Docs::from_attrs(&input.attrs);
+/// assert_eq!(docs.lines().collect::<Vec<_>>(), ["Does things.", "", "    let indented = true;"]);
+/// assert_eq!(docs.text(), "Does things.\n\n    let indented = true;");
+/// assert_eq!(docs.summary(), "Does things.");
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Docs(Vec<String>);
+
+/// Whether the attribute is a `#[doc = "..."]` doc comment, as opposed to e.g. `#[doc(hidden)]`
+pub(crate) fn is_doc_comment(attr: &Attribute) -> bool {
+    matches!(attr.meta, Meta::NameValue(ref meta) if meta.path.is_ident("doc"))
+}
+
+impl Docs {
+    /// Construct an empty instance
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Collect the doc comments from the given attributes, skipping any others
+    pub fn from_attrs<'a>(attrs: impl IntoIterator<Item = &'a Attribute>) -> Self {
+        let mut docs = Self::new();
+        for attr in attrs {
+            docs.push_attr(attr);
+        }
+        docs
+    }
+
+    /// Add the attribute's lines if it's a doc comment. Returns whether it was one.
+    pub fn push_attr(&mut self, attr: &Attribute) -> bool {
+        let Meta::NameValue(ref meta) = attr.meta else {
+            return false;
+        };
+        if !meta.path.is_ident("doc") {
+            return false;
+        }
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(ref lit),
+            ..
+        }) = meta.value
+        {
+            self.0.extend(lit.value().split('\n').map(String::from));
+        }
+
+        true
+    }
+
+    /// Whether there are no non-blank lines
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(move |line| line.trim().is_empty())
+    }
+
+    /// The normalised lines
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        let non_blank = move |line: &&String| !line.trim().is_empty();
+        let indent = self
+            .0
+            .iter()
+            .filter(non_blank)
+            .map(move |line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let start = self.0.iter().position(move |line| non_blank(&line));
+        let end = self.0.iter().rposition(move |line| non_blank(&line));
+        let lines = match (start, end) {
+            (Some(start), Some(end)) => &self.0[start..=end],
+            _ => &[],
+        };
+
+        lines.iter().map(move |line| {
+            line.get(indent..)
+                .unwrap_or_else(move || line.trim_start())
+                .trim_end()
+        })
+    }
+
+    /// The normalised lines joined by newlines
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.lines().collect::<Vec<_>>().join("\n")
+    }
+
+    /// The first paragraph, with its lines joined by spaces
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.lines()
+            .take_while(move |line| !line.is_empty())
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Display for Docs {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text())
+    }
+}
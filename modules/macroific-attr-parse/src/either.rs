@@ -0,0 +1,150 @@
+use proc_macro2::Span;
+use syn::parse::discouraged::Speculative;
+use syn::parse::ParseStream;
+use syn::{Error, Expr, Token};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::{collect_expected, expecting_any};
+
+/// An option that accepts one of two alternatives, e.g. `default = true` or
+/// `default = path::to_fn`. `A` gets tried first and the first alternative that parses wins.
+///
+/// Nest it for more alternatives: `Either<bool, Either<syn::Path, u32>>`. If all alternatives
+/// fail, the values they expect get combined into one error, e.g.
+/// `expected bool, path or integer`.
+///
+/// # Example
+///
+/// ```
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, Either};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   default: Option<Either<bool, Either<syn::Path, u32>>>,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(default = false)])).unwrap();
+/// assert!(matches!(opts.default, Some(Either::Left(false))));
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(default = 5)])).unwrap();
+/// assert!(matches!(opts.default, Some(Either::Right(Either::Right(5)))));
+///
+/// let err = MyOptions::from_attr(parse_quote!(#[my(default = 1.5)])).err().unwrap();
+/// assert_eq!(err.to_string(), "expected bool, path or integer");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Either<A, B> {
+    /// The first alternative
+    Left(A),
+
+    /// The second alternative
+    Right(B),
+}
+
+impl<A, B> Either<A, B> {
+    /// Get the first alternative, if that's what was parsed
+    #[inline]
+    pub fn left(self) -> Option<A> {
+        match self {
+            Self::Left(a) => Some(a),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Get the second alternative, if that's what was parsed
+    #[inline]
+    pub fn right(self) -> Option<B> {
+        match self {
+            Self::Left(_) => None,
+            Self::Right(b) => Some(b),
+        }
+    }
+}
+
+/// Combine what each alternative expects into one error, also passing it on to any outer
+/// [`Either`]. Alternatives that didn't say what they expect, e.g. derived structs, read as
+/// `value`.
+fn expected_error(span: Span, alternatives: [Vec<&'static str>; 2]) -> Error {
+    let mut names = Vec::new();
+    for expected in alternatives {
+        let expected = if expected.is_empty() {
+            vec!["value"]
+        } else {
+            expected
+        };
+        for name in expected {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    expecting_any(&names);
+
+    let msg = match names.split_last() {
+        Some((last, [])) => format!("expected {last}"),
+        Some((last, rest)) => format!("expected {} or {last}", rest.join(", ")),
+        None => "unexpected value".into(),
+    };
+
+    Error::new(span, msg)
+}
+
+/// Try parsing `T` on a fork, committing only if it consumed the entire value
+fn try_fork<T: ParseOption>(input: ParseStream) -> Option<T> {
+    let fork = input.fork();
+    let value = T::from_stream(&fork).ok()?;
+
+    if fork.is_empty() || fork.peek(Token![,]) {
+        input.advance_to(&fork);
+        Some(value)
+    } else {
+        None
+    }
+}
+
+impl<A: ParseOption, B: ParseOption> ParseOption for Either<A, B> {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        let (a, expected_a) = collect_expected(|| try_fork(input));
+        if let Some(a) = a {
+            return Ok(Self::Left(a));
+        }
+        let (b, expected_b) = collect_expected(|| try_fork(input));
+        if let Some(b) = b {
+            return Ok(Self::Right(b));
+        }
+
+        let span = if input.peek(Token![=]) {
+            let fork = input.fork();
+            fork.parse::<Token![=]>()?;
+            fork.span()
+        } else {
+            input.span()
+        };
+
+        Err(expected_error(span, [expected_a, expected_b]))
+    }
+}
+
+impl<A: FromExpr, B: FromExpr> FromExpr for Either<A, B> {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        let span = syn::spanned::Spanned::span(&expr);
+        let (a, expected_a) = collect_expected(|| A::from_expr(expr.clone()));
+        if let Ok(a) = a {
+            return Ok(Self::Left(a));
+        }
+        let (b, expected_b) = collect_expected(move || B::from_expr(expr));
+        if let Ok(b) = b {
+            return Ok(Self::Right(b));
+        }
+
+        Err(expected_error(span, [expected_a, expected_b]))
+    }
+
+    fn boolean() -> Option<Self> {
+        A::boolean()
+            .map(Self::Left)
+            .or_else(|| B::boolean().map(Self::Right))
+    }
+}
+
@@ -0,0 +1,41 @@
+//! Combining errors instead of stopping at the first one
+
+/// Both values, or the errors of whichever failed combined
+pub(crate) fn combine_results<A, B>(a: syn::Result<A>, b: syn::Result<B>) -> syn::Result<(A, B)> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (Err(e), Ok(_)) | (Ok(_), Err(e)) => Err(e),
+        (Err(mut a), Err(b)) => {
+            a.combine(b);
+            Err(a)
+        }
+    }
+}
+
+/// Collect the values, or all the errors combined
+pub(crate) fn collect_results<T, Out, I>(results: I) -> syn::Result<Out>
+where
+    Out: FromIterator<T>,
+    I: IntoIterator<Item = syn::Result<T>>,
+{
+    let mut error: Option<syn::Error> = None;
+    let out = results
+        .into_iter()
+        .filter_map(|result| match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                if let Some(ref mut error) = error {
+                    error.combine(e);
+                } else {
+                    error = Some(e);
+                }
+                None
+            }
+        })
+        .collect();
+
+    match error {
+        None => Ok(out),
+        Some(e) => Err(e),
+    }
+}
@@ -0,0 +1,243 @@
+use std::any::Any;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use proc_macro2::{Delimiter, Group, Ident, Span};
+use quote::ToTokens;
+use syn::parse::{ParseStream, Parser};
+use syn::{parenthesized, Error, Expr, LitStr};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::expecting;
+use crate::ungroup::ungroup;
+use crate::ValueSyntax;
+
+/// An option read from an environment variable while the macro runs: `key = env("VAR_NAME")`.
+/// A [`String`] gets the variable's value as is; other types parse it the way they'd parse
+/// `key(...)`, e.g. `8080` for a `u16`, `Vec<u8>` for a [`syn::Type`] or `a = 1, b = 2` for a
+/// derived [`ParseOption`] struct.
+///
+/// Missing variables & parse failures are reported at the variable name's span.
+///
+/// Cargo doesn't know the macro read the variable, so changing it won't trigger a rebuild. Have
+/// the crate's build script emit `cargo:rerun-if-env-changed=VAR_NAME` if that matters.
+///
+/// # Example
+///
+/// ```
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, EnvValue};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   name: Option<EnvValue>,
+///   major: Option<EnvValue<u16>>,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(
+///   name = env("CARGO_PKG_NAME"),
+///   major = env("CARGO_PKG_VERSION_MAJOR"),
+/// )]))
+/// .unwrap();
+///
+/// assert_eq!(*opts.name.unwrap(), "macroific_attr_parse");
+/// assert_eq!(Some(*opts.major.unwrap()), env!("CARGO_PKG_VERSION_MAJOR").parse().ok());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct EnvValue<T = String>(T);
+
+impl<T> EnvValue<T> {
+    /// Get the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ParseOption + 'static> EnvValue<T> {
+    fn from_name(name: &LitStr) -> syn::Result<Self> {
+        let var = name.value();
+        let value = env::var(&var).map_err(move |e| {
+            let msg = match e {
+                env::VarError::NotPresent => format!("Environment variable `{var}` is not set"),
+                env::VarError::NotUnicode(_) => {
+                    format!("Environment variable `{var}` is not valid unicode")
+                }
+            };
+            Error::new_spanned(name, msg)
+        })?;
+
+        match parse_contents(value, name.span()) {
+            Ok(value) => Ok(Self(value)),
+            Err(e) => Err(Error::new_spanned(
+                name,
+                format!(
+                    "Failed to parse environment variable `{}`: {e}",
+                    name.value()
+                ),
+            )),
+        }
+    }
+}
+
+/// The contents of a file read while the macro runs: `key = file("path/to/file")`. Relative
+/// paths are resolved against `CARGO_MANIFEST_DIR`, i.e. the root of the crate being compiled.
+/// The contents get taken or parsed the same way as [`EnvValue`]'s.
+///
+/// Files that can't be read or parsed are reported at the path's span.
+///
+/// Cargo doesn't know the macro read the file, so editing it won't trigger a rebuild. Either
+/// have the crate's build script emit `cargo:rerun-if-changed=path/to/file` or make the
+/// generated code `include_str!` the [path](Self::path) so the compiler tracks it.
+///
+/// # Example
+///
+/// ```
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, FileContents};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   schema: Option<FileContents>,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(schema = file("Cargo.toml"))])).unwrap();
+/// let schema = opts.schema.unwrap();
+///
+/// assert!(schema.contains("[package]"));
+/// assert!(schema.path().is_absolute());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FileContents<T = String> {
+    contents: T,
+    path: PathBuf,
+}
+
+impl<T> FileContents<T> {
+    /// The file's resolved path
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get the file's contents
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.contents
+    }
+}
+
+impl<T: ParseOption + 'static> FileContents<T> {
+    fn from_path(lit: &LitStr) -> syn::Result<Self> {
+        let relative = lit.value();
+        let path = match env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) => Path::new(&dir).join(&relative),
+            None => PathBuf::from(&relative),
+        };
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| Error::new_spanned(lit, format!("Failed to read `{relative}`: {e}")))?;
+
+        match parse_contents(contents, lit.span()) {
+            Ok(contents) => Ok(Self { contents, path }),
+            Err(e) => Err(Error::new_spanned(
+                lit,
+                format!("Failed to parse `{relative}`: {e}"),
+            )),
+        }
+    }
+}
+
+/// Take a [`String`] as is, parse anything else as if it were the contents of `key(...)`. The
+/// tokens get the given span.
+fn parse_contents<T: ParseOption + 'static>(contents: String, span: Span) -> syn::Result<T> {
+    let mut contents = Some(contents);
+    let as_is = (&mut contents as &mut dyn Any).downcast_mut::<Option<T>>();
+    if let Some(value) = as_is.and_then(Option::take) {
+        return Ok(value);
+    }
+
+    let lit = LitStr::new(&contents.unwrap_or_default(), span);
+    lit.parse_with(move |input: ParseStream| {
+        let mut group = Group::new(Delimiter::Parenthesis, input.parse()?);
+        group.set_span(span);
+        T::from_stream.parse2(group.into_token_stream())
+    })
+}
+
+/// Parse `func("arg")`
+fn parse_call(input: ParseStream, func: &str) -> syn::Result<LitStr> {
+    let ident = input.parse::<Ident>()?;
+    if ident != func {
+        let msg = format!("Expected `{func}(\"...\")`");
+        return Err(Error::new(ident.span(), msg));
+    }
+
+    let content;
+    parenthesized!(content in input);
+    content.parse()
+}
+
+fn stream_call(input: ParseStream, func: &str) -> syn::Result<LitStr> {
+    match ValueSyntax::from_stream(input) {
+        Some(syntax) => match syntax.parse_token(input)? {
+            Some(inner) => parse_call(&inner, func),
+            None => parse_call(input, func),
+        },
+        None => parse_call(input, func),
+    }
+}
+
+/// Works off tokens as call expressions require syn's `full` feature
+fn expr_call(expr: Expr, func: &str) -> syn::Result<LitStr> {
+    let parser = move |input: ParseStream| parse_call(input, func);
+    parser.parse2(ungroup(expr).into_token_stream())
+}
+
+impl<T: ParseOption + 'static> ParseOption for EnvValue<T> {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        expecting("`env(\"...\")`");
+        Self::from_name(&stream_call(input, "env")?)
+    }
+}
+
+impl<T: ParseOption + 'static> FromExpr for EnvValue<T> {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        expecting("`env(\"...\")`");
+        Self::from_name(&expr_call(expr, "env")?)
+    }
+}
+
+impl<T: ParseOption + 'static> ParseOption for FileContents<T> {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        expecting("`file(\"...\")`");
+        Self::from_path(&stream_call(input, "file")?)
+    }
+}
+
+impl<T: ParseOption + 'static> FromExpr for FileContents<T> {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        expecting("`file(\"...\")`");
+        Self::from_path(&expr_call(expr, "file")?)
+    }
+}
+
+impl<T> Deref for EnvValue<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Deref for FileContents<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.contents
+    }
+}
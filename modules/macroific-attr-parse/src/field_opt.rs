@@ -1,3 +1,4 @@
+use std::mem;
 use std::ops::{Deref, DerefMut};
 
 use proc_macro2::TokenStream;
@@ -6,7 +7,10 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{token, Attribute, Field, Fields, FieldsNamed, FieldsUnnamed, Token};
 
-use crate::AttributeOptions;
+use crate::cfg_features::collect_matching;
+use crate::docs::is_doc_comment;
+use crate::errors::collect_results;
+use crate::{AttrName, AttributeOptions, CfgFeatures};
 
 /// A [`Field`] with options [parsed](AttributeOptions).
 pub struct FieldWithOpts<O> {
@@ -37,9 +41,19 @@ pub enum FieldsWithOpts<O> {
 
 macro_rules! from_attr_name {
     ($input: ty) => {
-        /// [`from_predicate`](Self::from_predicate) shorthand for filtering by attribute name
+        /// [`from_predicate`](Self::from_predicate) shorthand for filtering by attribute name.
+        /// `#[cfg_attr(...)]`s are left alone; see
+        /// [`from_attr_name_cfg`](Self::from_attr_name_cfg) for evaluating them.
+        #[inline]
         pub fn from_attr_name(input: $input, attr_name: &str) -> ::syn::Result<Self> {
-            Self::from_predicate(input, move |attr| attr.path().is_ident(attr_name))
+            Self::from_attr_path(input, attr_name)
+        }
+
+        /// [`from_attr_name`](Self::from_attr_name) for any [`AttrName`], e.g.
+        /// `["myorg", "serde"]` for `#[myorg::serde(...)]`
+        #[inline]
+        pub fn from_attr_path(input: $input, attr_name: impl AttrName) -> ::syn::Result<Self> {
+            Self::from_attr_name_in(input, &attr_name, None)
         }
     };
 }
@@ -47,69 +61,173 @@ macro_rules! from_attr_name {
 impl<O: AttributeOptions> FieldWithOpts<O> {
     /// Construct from the given field using attributes the predicate returns true for. The remaining attributes will
     /// be kept on the field.
-    pub fn from_predicate<F>(mut field: Field, mut predicate: F) -> syn::Result<Self>
+    pub fn from_predicate<F>(field: Field, predicate: F) -> syn::Result<Self>
     where
         F: FnMut(&Attribute) -> bool,
+    {
+        let (this, _) = Self::from_predicates(field, predicate, move |_| false)?;
+        Ok(this)
+    }
+
+    /// [`from_predicate`](Self::from_predicate) that also moves the attributes `collect` returns
+    /// true for out of the field, e.g. `#[allow(...)]`s to copy onto generated code.
+    /// Attributes matching `predicate` are never collected.
+    pub fn from_predicates<F, C>(
+        mut field: Field,
+        mut predicate: F,
+        mut collect: C,
+    ) -> syn::Result<(Self, Vec<Attribute>)>
+    where
+        F: FnMut(&Attribute) -> bool,
+        C: FnMut(&Attribute) -> bool,
     {
         let mut relevant_attrs = Vec::new();
+        let mut collected = Vec::new();
         field.attrs = field
             .attrs
             .into_iter()
             .filter_map(|attr| {
                 if predicate(&attr) {
                     relevant_attrs.push(attr);
-                    None
+                } else if collect(&attr) {
+                    collected.push(attr);
                 } else {
-                    Some(attr)
+                    return Some(attr);
                 }
+                None
             })
             .collect();
+        push_passed_on::<O>(&field.attrs, &mut relevant_attrs);
+
+        let this = Self {
+            options: O::from_iter(field.span(), relevant_attrs)?,
+            field,
+        };
+        Ok((this, collected))
+    }
+
+    from_attr_name!(Field);
+
+    /// [`from_attr_path`](Self::from_attr_path) evaluating `#[cfg_attr(...)]` predicates against
+    /// the given features. Active `cfg_attr`s keep their remaining attributes on the field.
+    #[inline]
+    pub fn from_attr_name_cfg(
+        field: Field,
+        attr_name: impl AttrName,
+        features: &CfgFeatures,
+    ) -> syn::Result<Self> {
+        Self::from_attr_name_in(field, &attr_name, Some(features))
+    }
+
+    fn from_attr_name_in(
+        mut field: Field,
+        attr_name: &dyn AttrName,
+        features: Option<&CfgFeatures>,
+    ) -> syn::Result<Self> {
+        let relevant_attrs = take_options_attrs::<O>(&mut field.attrs, attr_name, features)?;
 
         Ok(Self {
             options: O::from_iter(field.span(), relevant_attrs)?,
             field,
         })
     }
+}
 
-    from_attr_name!(Field);
+/// Move the attributes matching `attr_name` out of `attrs`, unwrapping active `cfg_attr`s if
+/// there are `features` to evaluate them against, and copy in the doc comments & forwarded
+/// attributes `O` collects
+pub(crate) fn take_options_attrs<O: AttributeOptions>(
+    attrs: &mut Vec<Attribute>,
+    attr_name: &dyn AttrName,
+    features: Option<&CfgFeatures>,
+) -> syn::Result<Vec<Attribute>> {
+    let mut relevant_attrs = Vec::new();
+    let mut remaining = Vec::with_capacity(attrs.len());
+    for attr in mem::take(attrs) {
+        if let Some(attr) = collect_matching(features, attr, attr_name, &mut relevant_attrs)? {
+            remaining.push(attr);
+        }
+    }
+    *attrs = remaining;
+    push_passed_on::<O>(attrs, &mut relevant_attrs);
+
+    Ok(relevant_attrs)
+}
+
+/// Copy doc comments & forwarded attributes into the attributes to parse if the options collect
+/// them; they stay where they are
+fn push_passed_on<O: AttributeOptions>(source: &[Attribute], attrs: &mut Vec<Attribute>) {
+    let passed_on = source.iter().filter(move |attr| {
+        (O::__collects_docs() && is_doc_comment(attr)) || O::__forwards_attr(attr.path())
+    });
+    attrs.extend(passed_on.cloned());
 }
 
 impl<O: AttributeOptions> FieldsWithOpts<O> {
     /// Construct from the given fields using attributes the predicate returns true for. The remaining attributes will
-    /// be kept on the field.
+    /// be kept on the field. Errors from all the fields are combined.
     pub fn from_predicate<F>(fields: Fields, mut predicate: F) -> syn::Result<Self>
     where
         F: FnMut(&Attribute) -> bool,
+    {
+        Self::from_fields(fields, move |field| {
+            FieldWithOpts::from_predicate(field, &mut predicate)
+        })
+    }
+
+    from_attr_name!(Fields);
+
+    /// [`from_attr_path`](Self::from_attr_path) evaluating `#[cfg_attr(...)]` predicates against
+    /// the given features. Errors from all the fields are combined.
+    #[inline]
+    pub fn from_attr_name_cfg(
+        fields: Fields,
+        attr_name: impl AttrName,
+        features: &CfgFeatures,
+    ) -> syn::Result<Self> {
+        Self::from_attr_name_in(fields, &attr_name, Some(features))
+    }
+
+    pub(crate) fn from_attr_name_in(
+        fields: Fields,
+        attr_name: &dyn AttrName,
+        features: Option<&CfgFeatures>,
+    ) -> syn::Result<Self> {
+        Self::from_fields(fields, move |field| {
+            FieldWithOpts::from_attr_name_in(field, attr_name, features)
+        })
+    }
+
+    fn from_fields<F>(fields: Fields, mut parse: F) -> syn::Result<Self>
+    where
+        F: FnMut(Field) -> syn::Result<FieldWithOpts<O>>,
     {
         match fields {
-            Fields::Named(FieldsNamed { brace_token, named }) => {
-                let iter = named
-                    .into_iter()
-                    .map(|field| FieldWithOpts::from_predicate(field, &mut predicate));
-
-                Ok(Self::Named {
-                    fields: iter.collect::<syn::Result<_>>()?,
-                    brace_token,
-                })
-            }
+            Fields::Named(FieldsNamed { brace_token, named }) => Ok(Self::Named {
+                fields: collect_results(named.into_iter().map(&mut parse))?,
+                brace_token,
+            }),
             Fields::Unnamed(FieldsUnnamed {
                 paren_token,
                 unnamed,
-            }) => {
-                let fields = unnamed
-                    .into_iter()
-                    .map(|field| FieldWithOpts::from_predicate(field, &mut predicate));
-
-                Ok(Self::Unnamed {
-                    fields: fields.collect::<syn::Result<_>>()?,
-                    paren_token,
-                })
-            }
+            }) => Ok(Self::Unnamed {
+                fields: collect_results(unnamed.into_iter().map(&mut parse))?,
+                paren_token,
+            }),
             Fields::Unit => Ok(Self::Unit),
         }
     }
+}
 
-    from_attr_name!(Fields);
+impl<O> FieldsWithOpts<O> {
+    /// Iterate over the fields
+    pub fn iter(&self) -> impl Iterator<Item = &FieldWithOpts<O>> {
+        let fields = match *self {
+            Self::Named { ref fields, .. } | Self::Unnamed { ref fields, .. } => Some(fields),
+            Self::Unit => None,
+        };
+        fields.into_iter().flatten()
+    }
 }
 
 impl<O> ToTokens for FieldsWithOpts<O> {
@@ -0,0 +1,205 @@
+use proc_macro2::Span;
+use syn::parse::ParseStream;
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::OptionCtx;
+
+/// A boolean option that remembers where it was enabled.
+///
+/// | Value             | Result |
+/// | ----------------- | ------- |
+/// | _omitted_         | Unset |
+/// | `my_attr`         | Set at `my_attr`'s span |
+/// | `my_attr = true`  | Set at `my_attr`'s span |
+/// | `my_attr = false` | Unset |
+///
+/// Use [`TriState`] if an explicit `false` needs to be distinguished from an omitted option.
+///
+/// # Example
+///
+/// ```
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, Flag};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   skip: Flag,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(skip)])).unwrap();
+/// assert!(opts.skip.is_set());
+/// assert!(opts.skip.span().is_some());
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(skip = false)])).unwrap();
+/// assert!(!opts.skip.is_set());
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my()])).unwrap();
+/// assert!(!opts.skip.is_set());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Flag(Option<Span>);
+
+impl Flag {
+    /// A flag set at the given span
+    #[inline]
+    #[must_use]
+    pub const fn new(span: Span) -> Self {
+        Self(Some(span))
+    }
+
+    /// An unset flag
+    #[inline]
+    #[must_use]
+    pub const fn unset() -> Self {
+        Self(None)
+    }
+
+    /// `true` if the flag was enabled
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Span of the key that enabled the flag
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        self.0
+    }
+
+    fn from_bool(value: bool, key: Span) -> Self {
+        if value {
+            Self::new(key)
+        } else {
+            Self::unset()
+        }
+    }
+}
+
+impl From<Flag> for bool {
+    #[inline]
+    fn from(flag: Flag) -> Self {
+        flag.is_set()
+    }
+}
+
+/// A boolean option that distinguishes between being enabled, explicitly disabled & omitted.
+///
+/// | Value             | Result |
+/// | ----------------- | ------- |
+/// | _omitted_         | [`Absent`](TriState::Absent) |
+/// | `my_attr`         | [`Enabled`](TriState::Enabled) |
+/// | `my_attr = true`  | [`Enabled`](TriState::Enabled) |
+/// | `my_attr = false` | [`Disabled`](TriState::Disabled) |
+///
+/// # Example
+///
+/// ```
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, TriState};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   inline: TriState,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(inline)])).unwrap();
+/// assert_eq!(opts.inline.value(), Some(true));
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(inline = false)])).unwrap();
+/// assert_eq!(opts.inline.value(), Some(false));
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my()])).unwrap();
+/// assert_eq!(opts.inline.value(), None);
+/// assert!(opts.inline.unwrap_or(true)); // fall back to e.g. a container-level option
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TriState {
+    /// The option wasn't provided
+    #[default]
+    Absent,
+
+    /// The option was enabled at the given span
+    Enabled(Span),
+
+    /// The option was explicitly disabled at the given span
+    Disabled(Span),
+}
+
+impl TriState {
+    /// `Some(bool)` if the option was provided, `None` if it was omitted
+    #[must_use]
+    pub const fn value(&self) -> Option<bool> {
+        match *self {
+            Self::Absent => None,
+            Self::Enabled(_) => Some(true),
+            Self::Disabled(_) => Some(false),
+        }
+    }
+
+    /// The provided value or the given default if the option was omitted
+    #[inline]
+    #[must_use]
+    pub const fn unwrap_or(&self, default: bool) -> bool {
+        match self.value() {
+            Some(v) => v,
+            None => default,
+        }
+    }
+
+    /// `true` if the option wasn't provided
+    #[inline]
+    #[must_use]
+    pub const fn is_absent(&self) -> bool {
+        matches!(self, Self::Absent)
+    }
+
+    /// Span of the key the option was provided under
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        match *self {
+            Self::Absent => None,
+            Self::Enabled(span) | Self::Disabled(span) => Some(span),
+        }
+    }
+
+    fn from_bool(value: bool, key: Span) -> Self {
+        if value {
+            Self::Enabled(key)
+        } else {
+            Self::Disabled(key)
+        }
+    }
+}
+
+impl From<TriState> for Option<bool> {
+    #[inline]
+    fn from(value: TriState) -> Self {
+        value.value()
+    }
+}
+
+macro_rules! impl_bool_like {
+    ($($ty: ty),+) => {
+        $(
+            impl ParseOption for $ty {
+                fn from_stream(input: ParseStream) -> syn::Result<Self> {
+                    Ok(Self::from_bool(bool::from_stream(input)?, OptionCtx::current().key))
+                }
+            }
+
+            impl FromExpr for $ty {
+                fn from_expr(expr: syn::Expr) -> syn::Result<Self> {
+                    Ok(Self::from_bool(bool::from_expr(expr)?, OptionCtx::current().key))
+                }
+
+                fn boolean() -> Option<Self> {
+                    Some(Self::from_bool(true, OptionCtx::current().key))
+                }
+            }
+        )+
+    };
+}
+
+impl_bool_like!(Flag, TriState);
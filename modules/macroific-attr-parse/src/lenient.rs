@@ -0,0 +1,93 @@
+//! Value conversions for `#[attr_opts(lenient)]` options.
+
+use syn::parse::ParseStream;
+use syn::{Error, Expr, ExprLit, ExprPath, Lit, LitBool, LitStr, Result};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::OptionCtx;
+use crate::ungroup::unparen;
+use crate::ValueSyntax;
+
+/// Whether the option being parsed has `#[attr_opts(lenient)]` set
+#[inline]
+pub(crate) fn enabled() -> bool {
+    OptionCtx::current().lenient
+}
+
+/// Parse the value as an expression & convert it with [`FromExpr::from_expr`], which applies the
+/// lenient conversions
+pub(crate) fn from_stream<T: FromExpr>(input: ParseStream) -> Result<T> {
+    T::from_expr(ValueSyntax::from_stream(input).and_parse(input)?)
+}
+
+/// [`unquote`] the value if the option being parsed is lenient
+pub(crate) fn unquote_if_enabled(expr: Expr) -> Result<Expr> {
+    if enabled() {
+        unquote(expr)
+    } else {
+        Ok(expr)
+    }
+}
+
+/// Parse the contents of a string literal as an expression, e.g. `"3"` → `3`
+pub(crate) fn unquote(expr: Expr) -> Result<Expr> {
+    match unparen(expr) {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => lit.parse(),
+        expr => Ok(expr),
+    }
+}
+
+/// Accepts `"true"`, `"false"`, `1` & `0` on top of boolean literals
+pub(crate) fn boolean(expr: Expr) -> Result<bool> {
+    let expr = unparen(expr);
+    if let Expr::Lit(ExprLit { ref lit, .. }) = expr {
+        match *lit {
+            Lit::Str(ref lit) => {
+                return match lit.value().as_str() {
+                    "true" => Ok(true),
+                    "false" => Ok(false),
+                    _ => Err(Error::new_spanned(
+                        lit,
+                        "Expected `\"true\"` or `\"false\"`",
+                    )),
+                };
+            }
+            Lit::Int(ref lit) => {
+                return match lit.base10_digits() {
+                    "1" => Ok(true),
+                    "0" => Ok(false),
+                    _ => Err(Error::new_spanned(lit, "Expected `1` or `0`")),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    LitBool::from_expr(expr).map(move |lit| lit.value())
+}
+
+/// Accepts any literal & bare identifiers, converting them to their string representation
+pub(crate) fn string(expr: Expr) -> Result<String> {
+    let expr = unparen(expr);
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(lit) => Ok(lit.value()),
+            Lit::Int(lit) => Ok(lit.base10_digits().into()),
+            Lit::Float(lit) => Ok(lit.base10_digits().into()),
+            Lit::Bool(lit) => Ok(lit.value.to_string()),
+            Lit::Char(lit) => Ok(lit.value().into()),
+            lit => Err(Error::new_spanned(lit, "Incompatible literal")),
+        },
+        Expr::Path(ExprPath {
+            ref path,
+            qself: None,
+            ref attrs,
+        }) if attrs.is_empty() => match path.get_ident() {
+            Some(ident) => Ok(ident.to_string()),
+            None => LitStr::from_expr(expr).map(move |lit| lit.value()),
+        },
+        expr => LitStr::from_expr(expr).map(move |lit| lit.value()),
+    }
+}
@@ -11,14 +11,27 @@
 #![warn(missing_docs)]
 #![cfg_attr(doc_cfg, feature(doc_cfg, doc_auto_cfg))]
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Span, TokenStream};
 use syn::parse::ParseStream;
-use syn::punctuated::Punctuated;
-use syn::spanned::Spanned;
-use syn::{AttrStyle, MacroDelimiter, Meta, MetaList, PathArguments, PathSegment};
+use syn::spanned::Spanned as _;
+use syn::{Meta, MetaList};
 
+pub use attr_name::AttrName;
+pub use cfg_features::CfgFeatures;
 pub use delimited_iter::DelimitedIter;
+pub use derive_input::{
+    DataWithOpts, DeriveInputWithOpts, EnumWithOpts, StructWithOpts, VariantWithOpts,
+};
+pub use docs::Docs;
+pub use either::Either;
+pub use external::{EnvValue, FileContents};
+pub use flag::{Flag, TriState};
+pub use map::{MapKey, OrderedMap};
 pub use parse_wrapper::ParseWrapper;
+pub use presence::Presence;
+pub use spanned::Spanned;
+pub use str_lit::FromStrLit;
+pub use template::Template;
 pub use value_syntax::ValueSyntax;
 
 pub use field_opt::{FieldWithOpts, FieldsWithOpts};
@@ -30,9 +43,27 @@ mod parse_option_impls;
 #[doc(hidden)]
 mod parse_utils;
 mod parse_wrapper;
+mod presence;
+mod seq;
+mod spanned;
+mod str_lit;
+mod template;
+mod ungroup;
 
+mod attr_name;
+mod cfg_features;
 mod delimited_iter;
+mod derive_input;
+mod docs;
+mod either;
+mod errors;
+mod external;
 mod field_opt;
+mod flag;
+mod lenient;
+mod map;
+mod numeric;
+mod option_ctx;
 
 /// Options derivable from [`Attributes`](syn::Attribute).
 pub trait AttributeOptions: Sized {
@@ -42,7 +73,11 @@ pub trait AttributeOptions: Sized {
     }
 
     /// Shorthand for filtering attributes by name and passing them on to
-    /// [`from_iter`](Self::from_iter).
+    /// [`from_iter`](Self::from_iter). See [`from_iter_path`](Self::from_iter_path) for
+    /// matching namespaced attributes, e.g. `#[myorg::serde(...)]`.
+    ///
+    /// `#[cfg_attr(...)]`s are left alone: derive macros receive them already expanded. See
+    /// [`from_iter_named_cfg`](Self::from_iter_named_cfg) for evaluating them.
     ///
     /// The `span` is what will be used for printing errors if nothing more appropriate is
     /// available. It's likely the field or struct you're parsing.
@@ -51,12 +86,29 @@ pub trait AttributeOptions: Sized {
         span: Span,
         attributes: impl IntoIterator<Item = syn::Attribute>,
     ) -> syn::Result<Self> {
-        Self::from_iter(
-            span,
-            attributes
-                .into_iter()
-                .filter(move |a| a.path().is_ident(attr_name)),
-        )
+        Self::from_iter_path(attr_name, span, attributes)
+    }
+
+    /// [`from_iter_named`](Self::from_iter_named) for any [`AttrName`], e.g. `["myorg", "serde"]`
+    /// for `#[myorg::serde(...)]`
+    fn from_iter_path(
+        attr_name: impl AttrName,
+        span: Span,
+        attributes: impl IntoIterator<Item = syn::Attribute>,
+    ) -> syn::Result<Self> {
+        from_iter_named_in::<Self>(&attr_name, None, span, attributes)
+    }
+
+    /// [`from_iter_path`](Self::from_iter_path) evaluating `#[cfg_attr(...)]` predicates
+    /// against the given features: matching attributes inside an active `cfg_attr` are included,
+    /// ones inside an inactive one aren't.
+    fn from_iter_named_cfg(
+        attr_name: impl AttrName,
+        features: &CfgFeatures,
+        span: Span,
+        attributes: impl IntoIterator<Item = syn::Attribute>,
+    ) -> syn::Result<Self> {
+        from_iter_named_in::<Self>(&attr_name, Some(features), span, attributes)
     }
 
     /// Parse and construct from an iterator of attributes
@@ -70,27 +122,145 @@ pub trait AttributeOptions: Sized {
 
     /// Parse a stream containing options: `opt1(val1), opt2(val2)`
     fn from_stream(input: ParseStream) -> syn::Result<Self> {
-        Self::from_attr(syn::Attribute {
-            pound_token: Default::default(),
-            style: AttrStyle::Outer,
-            bracket_token: Default::default(),
-            meta: Meta::List(MetaList {
-                path: syn::Path {
-                    leading_colon: None,
-                    segments: {
-                        let mut segments = Punctuated::new();
-                        segments.push_value(PathSegment {
-                            ident: Ident::new("x", Span::call_site()),
-                            arguments: PathArguments::None,
-                        });
-                        segments
-                    },
-                },
-                delimiter: MacroDelimiter::Paren(Default::default()),
-                tokens: input.parse()?,
+        Self::from_args(input.parse()?)
+    }
+
+    /// Parse the `args` a [`#[proc_macro_attribute]`](https://doc.rust-lang.org/reference/procedural-macros.html#attribute-macros)
+    /// receives: `opt1(val1), opt2 = val2`. An empty stream is treated as no options being
+    /// provided.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use proc_macro2::TokenStream;
+    /// # use quote::quote;
+    /// use macroific::attr_parse::prelude::*;
+    ///
+    /// #[derive(AttributeOptions)]
+    /// struct MyOptions {
+    ///   count: Option<u8>,
+    /// }
+    ///
+    /// let opts = MyOptions::from_args(quote!(count = 5)).unwrap();
+    /// assert_eq!(opts.count, Some(5));
+    ///
+    /// let opts = MyOptions::from_args(TokenStream::new()).unwrap();
+    /// assert_eq!(opts.count, None);
+    /// ```
+    fn from_args(args: TokenStream) -> syn::Result<Self> {
+        Self::__from_nested_meta_sources(Span::call_site(), Some(args))
+    }
+
+    /// Parse the contents of a [`Meta`]. [`Meta::Path`] parses as if no options were provided,
+    /// [`Meta::NameValue`] errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use syn::{parse_quote, Meta};
+    /// use macroific::attr_parse::prelude::*;
+    ///
+    /// #[derive(AttributeOptions)]
+    /// struct MyOptions {
+    ///   count: Option<u8>,
+    /// }
+    ///
+    /// let meta: Meta = parse_quote!(my_opts(count = 5));
+    /// assert_eq!(MyOptions::from_meta(&meta).unwrap().count, Some(5));
+    ///
+    /// let meta: Meta = parse_quote!(my_opts);
+    /// assert_eq!(MyOptions::from_meta(&meta).unwrap().count, None);
+    /// ```
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        match *meta {
+            Meta::List(ref list) => Self::from_meta_list(list),
+            Meta::Path(ref path) => Self::from_meta_list(&MetaList {
+                path: path.clone(),
+                delimiter: syn::MacroDelimiter::Paren(syn::token::Paren(path.span())),
+                tokens: TokenStream::new(),
             }),
-        })
+            Meta::NameValue(ref meta) => Err(parse_utils::expected_list(meta)),
+        }
+    }
+
+    /// Parse the contents of a [`MetaList`], e.g. `my_opts(opt1(val1), opt2 = val2)`.
+    fn from_meta_list(meta: &MetaList) -> syn::Result<Self> {
+        Self::__from_nested_meta_sources(meta.span(), Some(meta))
+    }
+
+    /// Whether [`from_iter_named`](Self::from_iter_named) & friends should pass on `doc`
+    /// attributes too, i.e. the struct has a `#[attr_opts(docs)]` field
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn __collects_docs() -> bool {
+        false
     }
+
+    /// Whether [`from_iter_named`](Self::from_iter_named) & friends should pass on attributes
+    /// with this path too, i.e. it's listed in a `#[attr_opts(forward_attrs(...))]` field
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn __forwards_attr(path: &syn::Path) -> bool {
+        let _ = path;
+        false
+    }
+
+    #[doc(hidden)]
+    fn __from_nested_meta_sources<S: __private::NestedMetaSource>(
+        span: Span,
+        sources: impl IntoIterator<Item = S>,
+    ) -> syn::Result<Self> {
+        Self::from_iter(
+            span,
+            sources
+                .into_iter()
+                .map(__private::NestedMetaSource::into_attribute),
+        )
+    }
+}
+
+/// Collect the attributes matching `attr_name`, unwrapping active `cfg_attr`s if there are
+/// `features` to evaluate them against, along with the doc comments & forwarded attributes `O`
+/// collects
+fn from_iter_named_in<O: AttributeOptions>(
+    attr_name: &dyn AttrName,
+    features: Option<&CfgFeatures>,
+    span: Span,
+    attributes: impl IntoIterator<Item = syn::Attribute>,
+) -> syn::Result<O> {
+    let mut matching = Vec::new();
+    for attr in attributes {
+        let Some(mut attr) =
+            cfg_features::collect_matching(features, attr, attr_name, &mut matching)?
+        else {
+            continue;
+        };
+
+        if O::__collects_docs() {
+            if docs::is_doc_comment(&attr) {
+                matching.push(attr);
+                continue;
+            }
+
+            // Docs inside an active `cfg_attr`; other `doc` attributes, e.g. `#[doc(hidden)]`,
+            // aren't options
+            let mut docs = Vec::new();
+            let rest = cfg_features::collect_matching(features, attr, &"doc", &mut docs)?;
+            matching.extend(docs.into_iter().filter(docs::is_doc_comment));
+            let Some(rest) = rest else {
+                continue;
+            };
+            attr = rest;
+        }
+
+        if O::__forwards_attr(attr.path()) {
+            matching.push(attr);
+        }
+    }
+
+    O::from_iter(span, matching)
 }
 
 /// Makes a type usable for [`AttributeOptions`]
@@ -100,6 +270,24 @@ pub trait ParseOption: Sized {
 }
 
 /// Construct this type from an [`Expr`](syn::Expr).
+///
+/// The provided implementations look through invisible groups, which is how `macro_rules!`
+/// forwards fragments such as `$v:expr`, and through parentheses around literals.
+///
+/// # Example
+///
+/// ```
+/// # use macroific_attr_parse::__attr_parse_prelude::*;
+/// # use proc_macro2::{Delimiter, Group};
+/// # use quote::quote;
+/// #
+/// let group = Group::new(Delimiter::None, quote!("foo"));
+/// let expr: syn::Expr = syn::parse2(quote!(#group)).unwrap();
+/// assert!(matches!(expr, syn::Expr::Group(_)));
+/// assert_eq!(String::from_expr(expr).unwrap(), "foo");
+///
+/// assert_eq!(u8::from_expr(syn::parse_quote!((5))).unwrap(), 5);
+/// ```
 pub trait FromExpr: Sized {
     #[allow(missing_docs)]
     fn from_expr(expr: syn::Expr) -> syn::Result<Self>;
@@ -121,7 +309,13 @@ pub mod __attr_parse_prelude {
 #[doc(hidden)]
 pub mod __private {
     pub use crate::parse_utils::{
-        decode_attr_options_field, decode_parse_option_field, decode_parse_option_from_parse,
-        get_attr_ident, iterate_option_meta, MetaValue,
+        decode_attr_options_field, decode_attr_options_field_lenient,
+        decode_attr_options_from_expr, decode_from_expr_from_parse, decode_parse_option_field,
+        decode_parse_option_field_lenient, decode_parse_option_from_attr_options,
+        decode_parse_option_from_expr, decode_parse_option_from_parse, decode_positional_field,
+        decode_positional_field_lenient, expected_list, get_attr_ident, iterate_option_meta,
+        iterate_option_meta_in, iterate_option_meta_positional, parse_keyed_input,
+        parse_option_input, with_value_syntax, KeyedInput, MetaValue, NestedMetaSource,
+        OptionInput,
     };
 }
@@ -0,0 +1,250 @@
+//! Map option support: `key(a = 1, "b" = 2)`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use std::ops::Deref;
+
+use proc_macro2::{Ident, Span};
+use quote::ToTokens;
+use sealed::sealed;
+use syn::parse::{ParseStream, Parser};
+use syn::{Error, Expr, LitStr, Result, Token};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::{expecting, OptionCtx};
+use crate::seq::parse_delimited;
+use crate::ungroup::ungroup;
+
+/// A type usable as the key of a map option, e.g. `RUST_LOG` or `"x-a"` in
+/// `env(RUST_LOG = "info", "x-a" = 1)`.
+///
+/// | Key type   | `RUST_LOG` | `"x-a"` |
+/// | ---------- | ---------- | ------- |
+/// | [`String`] | ✅         | ✅      |
+/// | [`Ident`]  | ✅         | ✅ if the string is a valid identifier |
+///
+/// String keys compare by value, so `"a"` & `r"a"` are the same key.
+#[sealed]
+pub trait MapKey: Sized {
+    /// Parse the key
+    fn parse_key(input: ParseStream) -> Result<Self>;
+}
+
+#[sealed]
+impl MapKey for Ident {
+    fn parse_key(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            let lit = input.parse::<LitStr>()?;
+            let mut ident = lit.parse::<Ident>()?;
+            ident.set_span(lit.span());
+            Ok(ident)
+        } else {
+            parse_ident_key(input)
+        }
+    }
+}
+
+#[sealed]
+impl MapKey for String {
+    fn parse_key(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            Ok(input.parse::<LitStr>()?.value())
+        } else {
+            Ok(parse_ident_key(input)?.to_string())
+        }
+    }
+}
+
+fn parse_ident_key(input: ParseStream) -> Result<Ident> {
+    if input.peek(syn::Ident) {
+        input.parse()
+    } else {
+        Err(input.error("Expected an identifier or string literal key"))
+    }
+}
+
+/// A map option that keeps its entries in the order they were provided, e.g.
+/// `key(b = 1, a = 2)` yields `[("b", 1), ("a", 2)]`. Duplicate keys error like they do for
+/// [`HashMap`] & [`BTreeMap`].
+///
+/// # Example
+///
+/// ```
+/// # use macroific_attr_parse::__attr_parse_prelude::*;
+/// # use macroific_attr_parse::OrderedMap;
+/// # use quote::quote;
+/// # use syn::parse::Parser;
+/// #
+/// let map = OrderedMap::<String, u8>::from_stream.parse2(quote!((b = 1, "a" = 2))).unwrap();
+/// assert_eq!(*map, [("b".to_owned(), 1), ("a".to_owned(), 2)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrderedMap<K, V>(pub Vec<(K, V)>);
+
+impl<K, V> OrderedMap<K, V> {
+    /// Get the entries
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Vec<(K, V)> {
+        self.0
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: PartialEq,
+    {
+        if self.0.iter().any(|(k, _)| *k == key) {
+            Some(value)
+        } else {
+            self.0.push((key, value));
+            None
+        }
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<K, V> Deref for OrderedMap<K, V> {
+    type Target = [(K, V)];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Parse `(k1 = v1, k2 = v2)` entries, erroring on the first key `insert` reports as a duplicate
+fn parse_entries<K, V, M>(
+    input: ParseStream,
+    mut parse_value: impl FnMut(Span, ParseStream) -> Result<V>,
+    mut insert: impl FnMut(&mut M, K, V) -> Option<V>,
+) -> Result<M>
+where
+    K: MapKey,
+    M: Default,
+{
+    let (_, content) = parse_delimited(input)?;
+    let mut out = M::default();
+
+    while !content.is_empty() {
+        let span = content.span();
+        let key = K::parse_key(&content)?;
+        let value = parse_value(span, &content)?;
+
+        if insert(&mut out, key, value).is_some() {
+            return Err(Error::new(span, "Duplicate key"));
+        }
+
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(out)
+}
+
+/// Parse a value under its own key, keeping the map's leniency
+fn under_key<R>(key: Span, f: impl FnOnce() -> R) -> R {
+    OptionCtx::new(key, OptionCtx::current().lenient).scope(f)
+}
+
+fn from_stream<K, V, M>(
+    input: ParseStream,
+    insert: impl FnMut(&mut M, K, V) -> Option<V>,
+) -> Result<M>
+where
+    K: MapKey,
+    V: ParseOption,
+    M: Default,
+{
+    parse_entries(
+        input,
+        move |key, input| under_key(key, move || V::from_stream(input)),
+        insert,
+    )
+}
+
+/// Works off tokens as `k = v` pairs only parse as expressions with syn's `full` feature
+fn from_expr<K, V, M>(expr: Expr, insert: impl FnMut(&mut M, K, V) -> Option<V>) -> Result<M>
+where
+    K: MapKey,
+    V: FromExpr,
+    M: Default,
+{
+    let parse_value = move |key: Span, input: ParseStream| {
+        input.parse::<Token![=]>()?;
+        let expr = input.parse()?;
+        under_key(key, move || V::from_expr(expr))
+    };
+
+    let parser = move |input: ParseStream| parse_entries(input, parse_value, insert);
+    parser.parse2(ungroup(expr).into_token_stream())
+}
+
+impl<K, V, S> ParseOption for HashMap<K, V, S>
+where
+    K: MapKey + Eq + Hash,
+    V: ParseOption,
+    S: BuildHasher + Default,
+{
+    fn from_stream(input: ParseStream) -> Result<Self> {
+        expecting("map");
+        from_stream(input, Self::insert)
+    }
+}
+
+impl<K, V, S> FromExpr for HashMap<K, V, S>
+where
+    K: MapKey + Eq + Hash,
+    V: FromExpr,
+    S: BuildHasher + Default,
+{
+    fn from_expr(expr: Expr) -> Result<Self> {
+        expecting("map");
+        from_expr(expr, Self::insert)
+    }
+}
+
+impl<K: MapKey + Ord, V: ParseOption> ParseOption for BTreeMap<K, V> {
+    fn from_stream(input: ParseStream) -> Result<Self> {
+        expecting("map");
+        from_stream(input, Self::insert)
+    }
+}
+
+impl<K: MapKey + Ord, V: FromExpr> FromExpr for BTreeMap<K, V> {
+    fn from_expr(expr: Expr) -> Result<Self> {
+        expecting("map");
+        from_expr(expr, Self::insert)
+    }
+}
+
+impl<K: MapKey + PartialEq, V: ParseOption> ParseOption for OrderedMap<K, V> {
+    fn from_stream(input: ParseStream) -> Result<Self> {
+        expecting("map");
+        from_stream(input, Self::insert)
+    }
+}
+
+impl<K: MapKey + PartialEq, V: FromExpr> FromExpr for OrderedMap<K, V> {
+    fn from_expr(expr: Expr) -> Result<Self> {
+        expecting("map");
+        from_expr(expr, Self::insert)
+    }
+}
+
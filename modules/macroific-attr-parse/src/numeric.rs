@@ -0,0 +1,286 @@
+//! Constant folding for numeric option values, e.g. `-1`, `(4 * 1024)` or `1 << 3`.
+//!
+//! Integers error on overflow, [`Wrapping`] integers wrap around & floats evaluate every
+//! operand as a float, integer literals included, so `1 / 2` is `0.5`.
+
+use std::num::Wrapping;
+use std::str::FromStr;
+
+use syn::{BinOp, Error, Expr, ExprBinary, ExprGroup, ExprLit, ExprParen, ExprUnary, Lit, UnOp};
+
+/// Reasons an operation can fail
+pub(crate) enum EvalError {
+    Overflow,
+    DivisionByZero,
+    Unsupported,
+}
+
+impl EvalError {
+    fn into_syn_err(self, expr: &Expr) -> Error {
+        let msg = match self {
+            Self::Overflow => "Arithmetic overflow",
+            Self::DivisionByZero => "Division by zero",
+            Self::Unsupported => "Unsupported operation for this numeric type",
+        };
+
+        Error::new_spanned(expr, msg)
+    }
+}
+
+/// A number that can be evaluated from a constant expression
+pub(crate) trait Number: Copy {
+    /// Construct from a literal's digits. `negative` is set if the literal was negated.
+    fn from_lit(lit: &Lit, negative: bool, origin: &Expr) -> syn::Result<Self>;
+
+    fn neg(self) -> Result<Self, EvalError>;
+
+    fn not(self) -> Result<Self, EvalError>;
+
+    fn binary(self, op: &BinOp, rhs: Self) -> Result<Self, EvalError>;
+}
+
+/// Evaluate a constant numeric expression
+pub(crate) fn eval<N: Number>(expr: &Expr) -> syn::Result<N> {
+    match *expr {
+        Expr::Lit(ExprLit { ref lit, .. }) => N::from_lit(lit, false, expr),
+        Expr::Paren(ExprParen {
+            expr: ref inner, ..
+        })
+        | Expr::Group(ExprGroup {
+            expr: ref inner, ..
+        }) => eval(inner),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: ref inner,
+            ..
+        }) => {
+            if let Expr::Lit(ExprLit { ref lit, .. }) = **inner {
+                N::from_lit(lit, true, expr)
+            } else {
+                eval::<N>(inner)?
+                    .neg()
+                    .map_err(move |e| e.into_syn_err(expr))
+            }
+        }
+        Expr::Unary(ExprUnary {
+            op: UnOp::Not(_),
+            expr: ref inner,
+            ..
+        }) => eval::<N>(inner)?
+            .not()
+            .map_err(move |e| e.into_syn_err(expr)),
+        Expr::Binary(ExprBinary {
+            ref left,
+            ref op,
+            ref right,
+            ..
+        }) => {
+            let left = eval::<N>(left)?;
+            let right = eval::<N>(right)?;
+            left.binary(op, right)
+                .map_err(move |e| e.into_syn_err(expr))
+        }
+        _ => Err(Error::new_spanned(expr, "Incompatible expression")),
+    }
+}
+
+/// Split off a leading `-` syn may have included in a literal's digits
+fn split_sign(digits: &str, negative: bool) -> (bool, &str) {
+    match digits.strip_prefix('-') {
+        Some(digits) => (!negative, digits),
+        None => (negative, digits),
+    }
+}
+
+/// Reject literals suffixed with a type other than the one being parsed, e.g. `5u16` for a `u32`
+fn check_suffix(lit: &Lit, suffix: &str, expected: &str) -> syn::Result<()> {
+    if suffix.is_empty() || suffix == expected {
+        Ok(())
+    } else {
+        let msg = format!("Expected a `{expected}` literal, found a `{suffix}` suffix");
+        Err(Error::new_spanned(lit, msg))
+    }
+}
+
+fn parse_digits<N>(digits: &str, negative: bool, origin: &Expr, type_name: &str) -> syn::Result<N>
+where
+    N: FromStr,
+{
+    let parsed = if negative {
+        format!("-{digits}").parse()
+    } else {
+        digits.parse()
+    };
+
+    parsed.map_err(move |_| {
+        Error::new_spanned(origin, format!("Literal out of range for `{type_name}`"))
+    })
+}
+
+macro_rules! impl_int {
+    ($($ty: ty),+) => {
+        $(
+            impl Number for $ty {
+                fn from_lit(lit: &Lit, negative: bool, origin: &Expr) -> syn::Result<Self> {
+                    let Lit::Int(int) = lit else {
+                        return Err(Error::new_spanned(lit, "Incompatible literal"));
+                    };
+                    check_suffix(lit, int.suffix(), stringify!($ty))?;
+
+                    let (negative, digits) = split_sign(int.base10_digits(), negative);
+                    if negative && <$ty>::MIN == 0 && digits.bytes().any(move |b| b != b'0') {
+                        return Err(Error::new_spanned(
+                            origin,
+                            "Can't negate an unsigned integer",
+                        ));
+                    }
+
+                    let negative = negative && <$ty>::MIN != 0;
+                    parse_digits(digits, negative, origin, stringify!($ty))
+                }
+
+                #[inline]
+                fn neg(self) -> Result<Self, EvalError> {
+                    self.checked_neg().ok_or(EvalError::Overflow)
+                }
+
+                #[inline]
+                fn not(self) -> Result<Self, EvalError> {
+                    Ok(!self)
+                }
+
+                fn binary(self, op: &BinOp, rhs: Self) -> Result<Self, EvalError> {
+                    let out = match *op {
+                        BinOp::Add(_) => self.checked_add(rhs),
+                        BinOp::Sub(_) => self.checked_sub(rhs),
+                        BinOp::Mul(_) => self.checked_mul(rhs),
+                        BinOp::Div(_) | BinOp::Rem(_) if rhs == 0 => {
+                            return Err(EvalError::DivisionByZero);
+                        }
+                        BinOp::Div(_) => self.checked_div(rhs),
+                        BinOp::Rem(_) => self.checked_rem(rhs),
+                        BinOp::BitAnd(_) => Some(self & rhs),
+                        BinOp::BitOr(_) => Some(self | rhs),
+                        BinOp::BitXor(_) => Some(self ^ rhs),
+                        BinOp::Shl(_) => u32::try_from(rhs)
+                            .ok()
+                            .and_then(move |rhs| self.checked_shl(rhs)),
+                        BinOp::Shr(_) => u32::try_from(rhs)
+                            .ok()
+                            .and_then(move |rhs| self.checked_shr(rhs)),
+                        _ => return Err(EvalError::Unsupported),
+                    };
+
+                    out.ok_or(EvalError::Overflow)
+                }
+            }
+
+            /// Same as the wrapped type, but overflowing operations wrap around
+            impl Number for Wrapping<$ty> {
+                fn from_lit(lit: &Lit, negative: bool, origin: &Expr) -> syn::Result<Self> {
+                    if negative && <$ty>::MIN == 0 {
+                        <$ty>::from_lit(lit, false, origin).map(move |v| Wrapping(v.wrapping_neg()))
+                    } else {
+                        <$ty>::from_lit(lit, negative, origin).map(Wrapping)
+                    }
+                }
+
+                #[inline]
+                fn neg(self) -> Result<Self, EvalError> {
+                    Ok(-self)
+                }
+
+                #[inline]
+                fn not(self) -> Result<Self, EvalError> {
+                    Ok(!self)
+                }
+
+                #[allow(
+                    clippy::cast_lossless,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss
+                )]
+                fn binary(self, op: &BinOp, rhs: Self) -> Result<Self, EvalError> {
+                    Ok(match *op {
+                        BinOp::Add(_) => self + rhs,
+                        BinOp::Sub(_) => self - rhs,
+                        BinOp::Mul(_) => self * rhs,
+                        BinOp::Div(_) | BinOp::Rem(_) if rhs.0 == 0 => {
+                            return Err(EvalError::DivisionByZero);
+                        }
+                        BinOp::Div(_) => self / rhs,
+                        BinOp::Rem(_) => self % rhs,
+                        BinOp::BitAnd(_) => self & rhs,
+                        BinOp::BitOr(_) => self | rhs,
+                        BinOp::BitXor(_) => self ^ rhs,
+                        // Like `Wrapping`'s own shifts, only the low bits of `rhs` count
+                        BinOp::Shl(_) => Wrapping(self.0.wrapping_shl(rhs.0 as u32)),
+                        BinOp::Shr(_) => Wrapping(self.0.wrapping_shr(rhs.0 as u32)),
+                        _ => return Err(EvalError::Unsupported),
+                    })
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_float {
+    ($($ty: ty),+) => {
+        $(
+            impl Number for $ty {
+                fn from_lit(lit: &Lit, negative: bool, origin: &Expr) -> syn::Result<Self> {
+                    let (digits, suffix) = match *lit {
+                        Lit::Float(ref float) => (float.base10_digits(), float.suffix()),
+                        Lit::Int(ref int) => (int.base10_digits(), int.suffix()),
+                        _ => return Err(Error::new_spanned(lit, "Incompatible literal")),
+                    };
+                    check_suffix(lit, suffix, stringify!($ty))?;
+
+                    let (negative, digits) = split_sign(digits, negative);
+                    let out: Self = parse_digits(digits, negative, origin, stringify!($ty))?;
+
+                    if out.is_finite() {
+                        Ok(out)
+                    } else {
+                        let msg = concat!("Literal out of range for `", stringify!($ty), "`");
+                        Err(Error::new_spanned(origin, msg))
+                    }
+                }
+
+                #[inline]
+                fn neg(self) -> Result<Self, EvalError> {
+                    Ok(-self)
+                }
+
+                #[inline]
+                fn not(self) -> Result<Self, EvalError> {
+                    Err(EvalError::Unsupported)
+                }
+
+                fn binary(self, op: &BinOp, rhs: Self) -> Result<Self, EvalError> {
+                    let out = match *op {
+                        BinOp::Add(_) => self + rhs,
+                        BinOp::Sub(_) => self - rhs,
+                        BinOp::Mul(_) => self * rhs,
+                        BinOp::Div(_) | BinOp::Rem(_) if rhs == 0.0 => {
+                            return Err(EvalError::DivisionByZero);
+                        }
+                        BinOp::Div(_) => self / rhs,
+                        BinOp::Rem(_) => self % rhs,
+                        _ => return Err(EvalError::Unsupported),
+                    };
+
+                    if out.is_finite() {
+                        Ok(out)
+                    } else {
+                        Err(EvalError::Overflow)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_int!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+impl_float!(f32, f64);
+
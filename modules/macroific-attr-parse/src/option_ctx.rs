@@ -0,0 +1,88 @@
+//! What an option is being parsed under. The decode helpers the derives call set it & this
+//! crate's option types read it, so wrapper types, including ones outside this crate, don't have
+//! to pass anything on for leniency & key spans to reach the values they wrap.
+
+use std::cell::{Cell, RefCell};
+
+use proc_macro2::Span;
+
+thread_local! {
+    static CURRENT: Cell<Option<OptionCtx>> = const { Cell::new(None) };
+    static EXPECTED: RefCell<Option<Vec<&'static str>>> = const { RefCell::new(None) };
+}
+
+/// The key an option was provided under & whether `#[attr_opts(lenient)]` applies to it
+#[derive(Copy, Clone)]
+pub(crate) struct OptionCtx {
+    /// Span of the key, e.g. `foo` in `foo = "bar"`
+    pub key: Span,
+
+    /// Whether the value may be converted from another literal form, e.g. `"3"` for a number
+    pub lenient: bool,
+}
+
+impl OptionCtx {
+    #[inline]
+    pub const fn new(key: Span, lenient: bool) -> Self {
+        Self { key, lenient }
+    }
+
+    /// The context of the option being parsed: the call site & strict outside of one
+    pub fn current() -> Self {
+        CURRENT
+            .with(Cell::get)
+            .unwrap_or_else(move || Self::new(Span::call_site(), false))
+    }
+
+    /// Run `f` under this context, restoring the previous one afterwards. Options parsed within
+    /// start afresh for [`expecting`], e.g. an [`Either`](crate::Either) trying a struct doesn't
+    /// pick up what its fields expect.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        let _restore = Restore {
+            ctx: CURRENT.with(move |ctx| ctx.replace(Some(self))),
+            expected: EXPECTED.with(|expected| expected.replace(None)),
+        };
+
+        f()
+    }
+}
+
+struct Restore {
+    ctx: Option<OptionCtx>,
+    expected: Option<Vec<&'static str>>,
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.with(|ctx| ctx.set(self.ctx));
+        EXPECTED.with(|expected| *expected.borrow_mut() = self.expected.take());
+    }
+}
+
+/// Record what the option being parsed accepts, e.g. `bool`, for the error of an
+/// [`Either`](crate::Either) trying it. Only the outermost option counts, so a list reads as
+/// `list` rather than what its elements expect.
+#[inline]
+pub(crate) fn expecting(name: &'static str) {
+    expecting_any(&[name]);
+}
+
+/// [`expecting`] for options accepting several kinds of values
+pub(crate) fn expecting_any(names: &[&'static str]) {
+    EXPECTED.with(|expected| {
+        if let Some(ref mut expected) = *expected.borrow_mut() {
+            if expected.is_empty() {
+                expected.extend_from_slice(names);
+            }
+        }
+    });
+}
+
+/// Run `f`, collecting what the option it parses is [`expecting`]
+pub(crate) fn collect_expected<R>(f: impl FnOnce() -> R) -> (R, Vec<&'static str>) {
+    let outer = EXPECTED.with(|expected| expected.replace(Some(Vec::new())));
+    let out = f();
+    let names = EXPECTED.with(move |expected| expected.replace(outer));
+
+    (out, names.unwrap_or_default())
+}
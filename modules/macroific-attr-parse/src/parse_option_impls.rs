@@ -1,3 +1,9 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -7,17 +13,24 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::*;
 
-use crate::ValueSyntax;
 use crate::__attr_parse_prelude::*;
+use crate::option_ctx::expecting;
+use crate::seq::{split_expr_exact, Elements, Seq};
+use crate::ungroup::{ungroup, unparen};
+use crate::{lenient, numeric, ValueSyntax};
 
 impl ParseOption for bool {
     fn from_stream(input: ParseStream) -> Result<Self> {
-        input.parse_bool_attr()
+        expecting("bool");
+        if lenient::enabled() && ValueSyntax::from_stream(input).is_some() {
+            lenient::from_stream(input)
+        } else {
+            input.parse_bool_attr()
+        }
     }
 }
 
 impl<T: ParseOption, P: Parse> ParseOption for Punctuated<T, P> {
-    #[inline]
     fn from_stream(input: ParseStream) -> Result<Self> {
         if input.is_empty() {
             return Ok(Self::new());
@@ -25,23 +38,33 @@ impl<T: ParseOption, P: Parse> ParseOption for Punctuated<T, P> {
 
         let parse_buf;
         let parse_from: ParseStream;
-        match ValueSyntax::from_stream(input) {
+        match ValueSyntax::from_stream_in(
+            input,
+            &[ValueSyntax::Eq, ValueSyntax::Paren, ValueSyntax::Bracket],
+        )? {
+            // `= [a, b]`, or the legacy `= ([a, b])`
             Some(ValueSyntax::Eq) => {
                 input.parse::<Token![=]>()?;
-                let outer;
-                parenthesized!(outer in input);
-
-                bracketed!(parse_buf in outer);
-                parse_from = &parse_buf;
-            }
-            Some(ValueSyntax::Paren) => {
-                parenthesized!(parse_buf in input);
+                if input.peek(token::Paren) {
+                    let outer;
+                    parenthesized!(outer in input);
+                    bracketed!(parse_buf in outer);
+                } else {
+                    bracketed!(parse_buf in input);
+                }
                 parse_from = &parse_buf;
             }
+            Some(syntax) => match syntax.parse_token(input)? {
+                Some(buf) => {
+                    parse_buf = buf;
+                    parse_from = &parse_buf;
+                }
+                None => return Err(input.error("Expected a delimited list")),
+            },
             None => {
                 parse_from = input;
             }
-        };
+        }
 
         Self::parse_terminated_with(parse_from, ParseOption::from_stream)
     }
@@ -51,7 +74,7 @@ impl<T: FromExpr, P: Parse + Default> FromExpr for Punctuated<T, P> {
     #[cfg_attr(not(feature = "full"), inline, allow(unused_variables))]
     fn from_expr(expr: Expr) -> Result<Self> {
         #[cfg(feature = "full")]
-        match expr {
+        match ungroup(expr) {
             Expr::Array(ExprArray { elems, .. }) | Expr::Tuple(ExprTuple { elems, .. }) => {
                 let it = elems.into_iter().map(T::from_expr);
                 it.collect::<Result<_>>()
@@ -70,6 +93,20 @@ impl<T: FromExpr, P: Parse + Default> FromExpr for Punctuated<T, P> {
     }
 }
 
+impl<T: ParseOption, const N: usize> ParseOption for [T; N] {
+    fn from_stream(input: ParseStream) -> Result<Self> {
+        expecting("list");
+        Elements::from_stream(input)?.into_array()
+    }
+}
+
+impl<T: FromExpr, const N: usize> FromExpr for [T; N] {
+    fn from_expr(expr: Expr) -> Result<Self> {
+        expecting("list");
+        Elements::from_expr(expr)?.into_array()
+    }
+}
+
 impl<T: ParseOption> ParseOption for Option<T> {
     fn from_stream(input: ParseStream) -> Result<Self> {
         Ok(Some(T::from_stream(input)?))
@@ -103,13 +140,18 @@ impl FromExpr for TokenStream {
 
 impl FromExpr for Lit {
     fn from_expr(expr: Expr) -> Result<Self> {
-        Ok(ExprLit::from_expr(expr)?.lit)
+        Ok(ExprLit::from_expr(unparen(expr))?.lit)
     }
 }
 
 impl FromExpr for bool {
     fn from_expr(expr: Expr) -> Result<Self> {
-        Ok(<LitBool>::from_expr(expr)?.value())
+        expecting("bool");
+        if lenient::enabled() {
+            lenient::boolean(expr)
+        } else {
+            Ok(<LitBool>::from_expr(expr)?.value())
+        }
     }
 
     #[inline]
@@ -133,18 +175,13 @@ impl FromExpr for LitBool {
     }
 }
 
-impl FromExpr for Path {
-    fn from_expr(expr: Expr) -> Result<Self> {
-        Ok(<ExprPath>::from_expr(expr)?.path)
-    }
-}
-
 /// For use within the macro. API subject to change at any time.
 macro_rules! parse_impl {
-    (lit $([$base: ty, $lit: ty]),+) => {
+    (lit $([$base: ty, $lit: ty, $name: literal]),+) => {
         $(
             impl ParseOption for $base {
               fn from_stream(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+                  expecting($name);
                   Ok(<$lit as ParseOption>::from_stream(input)?.value())
                 }
             }
@@ -164,6 +201,10 @@ macro_rules! parse_impl {
                 fn from_expr(expr: Expr) -> Result<Self> {
                     T::from_expr(expr).map(<$ty>::new)
                 }
+
+                fn boolean() -> Option<Self> {
+                    T::boolean().map(<$ty>::new)
+                }
             }
         )+
     };
@@ -187,15 +228,110 @@ macro_rules! parse_impl {
             ValueSyntax::from_stream(input).and_parse(input)
           }
     };
-    (lit_num [$lit: ty => $($base: ty),+]) => {
+    (num $name: literal [$($base: ty),+]) => {
         $(
             impl ParseOption for $base {
               fn from_stream(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
-                <$lit as ParseOption>::from_stream(input)?.base10_parse()
+                Self::from_expr(ValueSyntax::from_stream(input).and_parse(input)?)
               }
             }
 
-            from_expr!(lit_direct_num $base, base10_parse => $lit);
+            impl FromExpr for $base {
+                fn from_expr(expr: Expr) -> ::syn::Result<Self> {
+                    expecting($name);
+                    numeric::eval(&lenient::unquote_if_enabled(expr)?)
+                }
+            }
+        )+
+    };
+    (non_zero [$($base: ty),+]) => {
+        $(
+            impl ParseOption for $base {
+              fn from_stream(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+                Self::from_expr(ValueSyntax::from_stream(input).and_parse(input)?)
+              }
+            }
+
+            impl FromExpr for $base {
+                fn from_expr(expr: Expr) -> ::syn::Result<Self> {
+                    expecting("non-zero integer");
+                    let expr = lenient::unquote_if_enabled(expr)?;
+                    Self::new(numeric::eval(&expr)?)
+                        .ok_or_else(move || Error::new_spanned(expr, "Value can't be zero"))
+                }
+            }
+        )+
+    };
+    (seq [$($ty: ident),+]) => {
+        $(
+            impl<T: ParseOption> ParseOption for $ty<T> {
+                fn from_stream(input: ParseStream) -> Result<Self> {
+                    expecting("list");
+                    Ok(Elements::from_stream(input)?.values().collect())
+                }
+            }
+
+            impl<T: FromExpr> FromExpr for $ty<T> {
+                fn from_expr(expr: Expr) -> Result<Self> {
+                    expecting("list");
+                    Ok(Elements::from_expr(expr)?.values().collect())
+                }
+            }
+        )+
+    };
+    (set [$($ty: ident<$($bound: path),+> $(, $hasher: ident)?);+]) => {
+        $(
+            impl<T: ParseOption $(+ $bound)+ $(, $hasher: BuildHasher + Default)?> ParseOption for $ty<T $(, $hasher)?> {
+                fn from_stream(input: ParseStream) -> Result<Self> {
+                    expecting("list");
+                    Elements::from_stream(input)?.collect_unique(Self::insert)
+                }
+            }
+
+            impl<T: FromExpr $(+ $bound)+ $(, $hasher: BuildHasher + Default)?> FromExpr for $ty<T $(, $hasher)?> {
+                fn from_expr(expr: Expr) -> Result<Self> {
+                    expecting("list");
+                    Elements::from_expr(expr)?.collect_unique(Self::insert)
+                }
+            }
+        )+
+    };
+    (tuple $([$len: literal => $(($ty: ident, $var: ident)),+]),+) => {
+        $(
+            impl<$($ty: ParseOption),+> ParseOption for ($($ty,)+) {
+                fn from_stream(input: ParseStream) -> Result<Self> {
+                    expecting("tuple");
+                    let mut seq = Seq::parse(input)?;
+                    let out = ($(seq.next_required::<$ty>($len)?,)+);
+                    seq.finish($len)?;
+
+                    Ok(out)
+                }
+            }
+
+            impl<$($ty: FromExpr),+> FromExpr for ($($ty,)+) {
+                fn from_expr(expr: Expr) -> Result<Self> {
+                    expecting("tuple");
+                    let [$($var),+] = split_expr_exact::<$len>(expr)?;
+                    Ok(($($ty::from_expr($var)?,)+))
+                }
+            }
+        )+
+    };
+    (wrapping [$($base: ty),+]) => {
+        $(
+            impl ParseOption for Wrapping<$base> {
+              fn from_stream(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+                Self::from_expr(ValueSyntax::from_stream(input).and_parse(input)?)
+              }
+            }
+
+            impl FromExpr for Wrapping<$base> {
+                fn from_expr(expr: Expr) -> ::syn::Result<Self> {
+                    expecting("integer");
+                    numeric::eval(&lenient::unquote_if_enabled(expr)?)
+                }
+            }
         )+
     };
 }
@@ -205,6 +341,11 @@ macro_rules! from_expr {
         $(
             impl FromExpr for $target {
                 fn from_expr(expr: Expr) -> Result<Self> {
+                    let expr = match expr {
+                        Expr::$ident(expr) => return Ok(expr),
+                        expr => ungroup(expr),
+                    };
+
                     if let Expr::$ident(expr) = expr {
                         Ok(expr)
                     } else {
@@ -228,13 +369,6 @@ macro_rules! from_expr {
                 }
             )+
     };
-    (lit_direct_num $base: ty, $fn: ident => $lit: ty) => {
-        impl FromExpr for $base {
-            fn from_expr(expr: Expr) -> ::syn::Result<Self> {
-                <$lit>::from_expr(expr)?.$fn()
-            }
-        }
-    };
     (lit_direct_num_ok $base: ty, $fn: ident => $lit: ty) => {
         impl FromExpr for $base {
             fn from_expr(expr: Expr) -> ::syn::Result<Self> {
@@ -246,29 +380,70 @@ macro_rules! from_expr {
         $(
             impl FromExpr for $ident {
                 fn from_expr(expr: Expr) -> Result<Self> {
-                    parse2(expr.into_token_stream())
+                    parse2(ungroup(expr).into_token_stream())
                 }
             }
         )+
     };
 }
 
-from_expr!(tokenise [Lifetime, LifetimeParam, BoundLifetimes, TypeParamBound, TraitBound, TypeParam, GenericParam, WherePredicate]);
-from_expr!(tokenise [Ident, Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer, TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple, AngleBracketedGenericArguments, ConstParam, Abi, BareFnArg, Meta, MetaList, MetaNameValue, Visibility]);
+from_expr!(tokenise [Lifetime, LifetimeParam, BoundLifetimes, TypeParamBound, TraitBound, TypeParam, GenericParam]);
+from_expr!(tokenise [TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer, TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple, AngleBracketedGenericArguments, ConstParam, Abi, BareFnArg, Meta, MetaList, MetaNameValue, Visibility]);
 from_expr!(lit [Str => LitStr], [ByteStr => LitByteStr], [Byte => LitByte], [Char => LitChar], [Int => LitInt], [Float => LitFloat], [Verbatim => Literal]);
 from_expr!(direct [Array => ExprArray], [Assign => ExprAssign], [Async => ExprAsync], [Await => ExprAwait], [Binary => ExprBinary], [Block => ExprBlock], [Break => ExprBreak], [Call => ExprCall], [Cast => ExprCast], [Closure => ExprClosure], [Const => ExprConst], [Continue => ExprContinue], [Field => ExprField], [ForLoop => ExprForLoop], [Group => ExprGroup], [If => ExprIf], [Infer => ExprInfer], [Index => ExprIndex], [Let => ExprLet], [Lit => ExprLit], [Loop => ExprLoop], [Macro => ExprMacro], [Match => ExprMatch], [MethodCall => ExprMethodCall], [Paren => ExprParen], [Path => ExprPath], [Range => ExprRange], [Reference => ExprReference], [Repeat => ExprRepeat], [Return => ExprReturn], [Struct => ExprStruct], [Try => ExprTry], [TryBlock => ExprTryBlock], [Tuple => ExprTuple], [Unary => ExprUnary], [Unsafe => ExprUnsafe], [While => ExprWhile], [Yield => ExprYield]);
 
-parse_impl!(lit [String, LitStr], [char, LitChar]);
+parse_impl!(lit [char, LitChar, "char"]);
 
-parse_impl!(lit_num [LitFloat => f32, f64]);
-parse_impl!(lit_num [LitInt => u8, i8, u16, i16, u32, i32, u64, i64, usize, isize]);
+impl ParseOption for String {
+    fn from_stream(input: ParseStream) -> Result<Self> {
+        expecting("string");
+        if lenient::enabled() {
+            lenient::from_stream(input)
+        } else {
+            Ok(LitStr::from_stream(input)?.value())
+        }
+    }
+}
 
-parse_impl!(parse [Expr, AngleBracketedGenericArguments, ConstParam, Abi, BareFnArg, Ident, Path, Meta, MetaList, MetaNameValue, Visibility]);
-parse_impl!(parse [Lifetime, LifetimeParam, BoundLifetimes, TypeParamBound, TraitBound, TypeParam, GenericParam, WherePredicate]);
+impl FromExpr for String {
+    fn from_expr(expr: Expr) -> Result<Self> {
+        expecting("string");
+        if lenient::enabled() {
+            lenient::string(expr)
+        } else {
+            Ok(LitStr::from_expr(expr)?.value())
+        }
+    }
+}
+
+parse_impl!(num "float" [f32, f64]);
+parse_impl!(num "integer" [u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize]);
+parse_impl!(non_zero [NonZeroU8, NonZeroI8, NonZeroU16, NonZeroI16, NonZeroU32, NonZeroI32, NonZeroU64, NonZeroI64, NonZeroU128, NonZeroI128, NonZeroUsize, NonZeroIsize]);
+parse_impl!(wrapping [u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize]);
+
+parse_impl!(parse [Expr, AngleBracketedGenericArguments, ConstParam, Abi, BareFnArg, Meta, MetaList, MetaNameValue, Visibility]);
+parse_impl!(parse [Lifetime, LifetimeParam, BoundLifetimes, TypeParamBound, TraitBound, TypeParam, GenericParam]);
 parse_impl!(parse [Lit, LitBool, LitByteStr, LitByte, LitStr, LitChar, LitInt, LitFloat, Literal]);
-parse_impl!(parse [Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer, TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple]);
+parse_impl!(parse [TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer, TypeMacro, TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple]);
 
 parse_impl!(new [Box<T>, Rc<T>, Arc<T>]);
+parse_impl!(seq [Vec, VecDeque]);
+parse_impl!(set [HashSet<Eq, Hash>, S; BTreeSet<Ord>]);
+parse_impl!(tuple
+    [1 => (A, a)],
+    [2 => (A, a), (B, b)],
+    [3 => (A, a), (B, b), (C, c)],
+    [4 => (A, a), (B, b), (C, c), (D, d)],
+    [5 => (A, a), (B, b), (C, c), (D, d), (E, e)],
+    [6 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f)],
+    [7 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f), (G, g)],
+    [8 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f), (G, g), (H, h)],
+    [9 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f), (G, g), (H, h), (I, i)],
+    [10 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f), (G, g), (H, h), (I, i), (J, j)],
+    [11 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f), (G, g), (H, h), (I, i), (J, j), (K, k)],
+    [12 => (A, a), (B, b), (C, c), (D, d), (E, e), (F, f), (G, g), (H, h), (I, i), (J, j), (K, k), (L, l)]
+);
 
 #[cfg(feature = "full")]
 parse_impl!(parse if "full" [ExprArray, ExprAssign, ExprAsync, ExprAwait, ExprBinary, ExprBlock, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField, ExprForLoop, ExprIf, ExprIndex, ExprInfer, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall, ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield]);
+
@@ -1,10 +1,21 @@
-use proc_macro2::{Ident, TokenStream};
-use syn::parse::{Parse, ParseStream};
+use std::iter;
+
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream};
+use quote::ToTokens;
+use sealed::sealed;
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::{Parse, ParseBuffer, ParseStream, Parser};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Meta, Token};
+use syn::{
+    token, AttrStyle, MacroDelimiter, Member, Meta, MetaList, MetaNameValue, PathArguments,
+    PathSegment, Token,
+};
 
 use crate::__attr_parse_prelude::*;
-use crate::{DelimitedIter, ParseWrapper, ValueSyntax};
+use crate::option_ctx::OptionCtx;
+use crate::{DelimitedIter, Docs, ParseWrapper, ValueSyntax};
 
 /// Get the ident from a path or [`Err`] trying
 pub fn get_attr_ident(path: &syn::Path) -> syn::Result<&Ident> {
@@ -15,6 +26,134 @@ pub fn get_attr_ident(path: &syn::Path) -> syn::Result<&Ident> {
     }
 }
 
+/// Something that holds a comma-separated list of options for the `AttributeOptions` derive macro
+#[sealed]
+pub trait NestedMetaSource {
+    /// Run the parser over each of the contained options
+    fn parse_nested_meta<F>(self, logic: F) -> syn::Result<()>
+    where
+        F: FnMut(ParseNestedMeta) -> syn::Result<()>;
+
+    /// Convert into an attribute for `AttributeOptions` implementations that only handle those
+    fn into_attribute(self) -> syn::Attribute;
+
+    /// Add the source to the docs if it's a doc comment, return it back otherwise
+    #[inline]
+    fn take_docs(self, docs: &mut Docs) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let _ = docs;
+        Some(self)
+    }
+
+    /// Move the source into `out` if it's an attribute `forwards` returns true for, return it
+    /// back otherwise
+    #[inline]
+    fn take_forwarded<F>(self, forwards: F, out: &mut Vec<syn::Attribute>) -> Option<Self>
+    where
+        Self: Sized,
+        F: FnOnce(&syn::Path) -> bool,
+    {
+        let _ = (forwards, out);
+        Some(self)
+    }
+}
+
+#[sealed]
+impl NestedMetaSource for syn::Attribute {
+    /// A bare `#[my]` has no options to parse
+    fn parse_nested_meta<F>(self, logic: F) -> syn::Result<()>
+    where
+        F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+    {
+        match self.meta {
+            Meta::List(list) => parse_keyed(list.tokens, false, logic),
+            Meta::Path(_) => Ok(()),
+            Meta::NameValue(ref meta) => Err(expected_list(meta)),
+        }
+    }
+
+    #[inline]
+    fn into_attribute(self) -> syn::Attribute {
+        self
+    }
+
+    fn take_docs(self, docs: &mut Docs) -> Option<Self> {
+        if docs.push_attr(&self) {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    fn take_forwarded<F>(self, forwards: F, out: &mut Vec<syn::Attribute>) -> Option<Self>
+    where
+        F: FnOnce(&syn::Path) -> bool,
+    {
+        if forwards(self.path()) {
+            out.push(self);
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+#[sealed]
+impl NestedMetaSource for &MetaList {
+    #[inline]
+    fn parse_nested_meta<F>(self, logic: F) -> syn::Result<()>
+    where
+        F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+    {
+        parse_keyed(self.tokens.clone(), false, logic)
+    }
+
+    fn into_attribute(self) -> syn::Attribute {
+        syn::Attribute {
+            pound_token: Default::default(),
+            style: AttrStyle::Outer,
+            bracket_token: Default::default(),
+            meta: Meta::List(self.clone()),
+        }
+    }
+}
+
+#[sealed]
+impl NestedMetaSource for TokenStream {
+    #[inline]
+    fn parse_nested_meta<F>(self, logic: F) -> syn::Result<()>
+    where
+        F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+    {
+        parse_keyed(self, false, logic)
+    }
+
+    /// Args have no attribute path, so this wraps them in a synthetic `x(...)` spanned to the
+    /// args themselves; manual `from_iter` implementations shouldn't rely on the path.
+    fn into_attribute(self) -> syn::Attribute {
+        let span = self.span();
+        let list = MetaList {
+            path: syn::Path {
+                leading_colon: None,
+                segments: {
+                    let mut segments = Punctuated::new();
+                    segments.push_value(PathSegment {
+                        ident: Ident::new("x", span),
+                        arguments: PathArguments::None,
+                    });
+                    segments
+                },
+            },
+            delimiter: MacroDelimiter::Paren(syn::token::Paren(span)),
+            tokens: self,
+        };
+
+        (&list).into_attribute()
+    }
+}
+
 pub enum MetaValue {
     Expr(syn::Expr),
     Stream(TokenStream),
@@ -22,36 +161,473 @@ pub enum MetaValue {
 
 pub type MetaValueTuple = (Ident, Option<MetaValue>);
 
-/// Iterate over metadata for the `ParseOption` derive macro
-pub fn iterate_option_meta(
-    parse: ParseStream,
-) -> syn::Result<impl Iterator<Item = syn::Result<MetaValueTuple>> + '_> {
-    fn map_meta(meta: Meta) -> syn::Result<MetaValueTuple> {
-        Ok(match meta {
-            Meta::Path(path) => (get_attr_ident(&path)?.clone(), None),
-            Meta::NameValue(meta) => (
-                get_attr_ident(&meta.path)?.clone(),
-                Some(MetaValue::Expr(meta.value)),
-            ),
-            Meta::List(meta) => (
-                get_attr_ident(&meta.path)?.clone(),
-                Some(MetaValue::Stream(meta.tokens)),
-            ),
+/// An option's key & value. Like [`Meta`], but keys may be keywords, e.g. `type = u8`, & the
+/// `key: value`, `key[value]` & `key { value }` syntaxes are accepted too. `key = value` pairs
+/// whose value starts with an invisible group, e.g. a `macro_rules!`-forwarded `$t:ty`, are kept
+/// as tokens as they don't necessarily parse as expressions.
+///
+/// Dotted & path keys, e.g. `server.port = 80`, are `nested`: their value holds the rest of the
+/// option, `port = 80`.
+struct OptionMeta {
+    meta: MetaValueTuple,
+    syntax: Option<(ValueSyntax, Span)>,
+    nested: bool,
+}
+
+impl Parse for OptionMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.call(parse_meta_path)?;
+        if let Some((ident, rest)) = split_nested_key(&path, input)? {
+            return Ok(Self {
+                meta: (ident, Some(MetaValue::Stream(rest))),
+                syntax: None,
+                nested: true,
+            });
+        }
+
+        let ident = get_attr_ident(&path)?.clone();
+        let span = input.span();
+        let syntax = ValueSyntax::peek(input);
+
+        let value = match syntax {
+            None => None,
+            Some(ValueSyntax::Eq) if input.peek2(syn::token::Group) => {
+                Some(MetaValue::Stream(take_value(input)?))
+            }
+            Some(ValueSyntax::Eq) => {
+                input.parse::<Token![=]>()?;
+                Some(MetaValue::Expr(input.parse()?))
+            }
+            Some(ValueSyntax::Colon) => {
+                let colon = input.parse::<Token![:]>()?;
+                let mut tokens = syn::token::Eq(colon.span).into_token_stream();
+                tokens.extend(take_value(input)?);
+                Some(MetaValue::Stream(tokens))
+            }
+            Some(syntax) => Some(MetaValue::Stream(paren(span, syntax.parse(input)?))),
+        };
+
+        Ok(Self {
+            meta: (ident, value),
+            syntax: syntax.map(move |syntax| (syntax, span)),
+            nested: false,
         })
     }
+}
+
+/// Parse a [`Meta`]'s path, which may contain keywords
+fn parse_meta_path(input: ParseStream) -> syn::Result<syn::Path> {
+    let mut path = syn::Path {
+        leading_colon: input.parse()?,
+        segments: Punctuated::new(),
+    };
+
+    loop {
+        path.segments.push_value(Ident::parse_any(input)?.into());
+        if !input.peek(Token![::]) {
+            return Ok(path);
+        }
+        path.segments.push_punct(input.parse()?);
+    }
+}
+
+/// Split a dotted or path key, e.g. `server.port = 80` or `server::port = 80`, into its first
+/// segment & the rest of the option, `port = 80`. `path` is the already-parsed key.
+fn split_nested_key(
+    path: &syn::Path,
+    input: ParseStream,
+) -> syn::Result<Option<(Ident, TokenStream)>> {
+    let mut rest = TokenStream::new();
+    let ident = if path.leading_colon.is_none() && path.segments.len() > 1 {
+        for pair in path.segments.pairs().skip(1) {
+            pair.to_tokens(&mut rest);
+        }
+        path.segments[0].ident.clone()
+    } else if input.peek(Token![.]) && !input.peek(Token![..]) {
+        input.parse::<Token![.]>()?;
+        get_attr_ident(path)?.clone()
+    } else {
+        return Ok(None);
+    };
+
+    rest.extend(take_value(input)?);
+    Ok(Some((ident, rest)))
+}
 
-    let parse_from: DelimitedIter<Meta, Token![,]> =
-        if let Some(syntax) = ValueSyntax::from_stream(parse) {
-            if let Some(buffer) = syntax.parse_token(parse)? {
-                buffer.into()
-            } else {
-                parse.into()
+/// Options provided through dotted or path keys, collected by their first segment so they can be
+/// passed on as `server(port = 80, host = "localhost")`
+#[derive(Default)]
+struct NestedKeys(Vec<(Ident, TokenStream)>);
+
+impl NestedKeys {
+    fn push(&mut self, ident: Ident, rest: TokenStream) {
+        let key = ident.unraw();
+        match self
+            .0
+            .iter_mut()
+            .find(move |(existing, _)| existing.unraw() == key)
+        {
+            Some((_, tokens)) => {
+                <Token![,]>::default().to_tokens(tokens);
+                tokens.extend(rest);
             }
+            None => self.0.push((ident, rest)),
+        }
+    }
+
+    /// Take the first collected key & its options wrapped in parentheses
+    fn pop_front(&mut self) -> Option<(Ident, TokenStream)> {
+        if self.0.is_empty() {
+            None
+        } else {
+            let (ident, tokens) = self.0.remove(0);
+            let tokens = paren(ident.span(), tokens);
+            Some((ident, tokens))
+        }
+    }
+}
+
+/// Run `logic` over the options in `tokens`. Options with dotted or path keys get passed on as
+/// `key(rest, ...)` once the rest have been parsed.
+fn parse_keyed<F>(tokens: TokenStream, positional: bool, mut logic: F) -> syn::Result<()>
+where
+    F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+{
+    let mut nested = NestedKeys::default();
+    let parser = syn::meta::parser(|meta| {
+        let input = meta.input;
+        match split_nested_key(&meta.path, input)? {
+            Some((ident, rest)) => nested.push(ident, rest),
+            None => logic(meta)?,
+        }
+
+        if positional {
+            ensure_keyed_next(input)
         } else {
+            Ok(())
+        }
+    });
+    parser.parse2(tokens)?;
+
+    let mut tokens = TokenStream::new();
+    while let Some((ident, value)) = nested.pop_front() {
+        ident.to_tokens(&mut tokens);
+        tokens.extend(value);
+        <Token![,]>::default().to_tokens(&mut tokens);
+    }
+
+    if tokens.is_empty() {
+        Ok(())
+    } else {
+        syn::meta::parser(logic).parse2(tokens)
+    }
+}
+
+/// Collect the tokens up to the next comma
+fn take_value(input: ParseStream) -> syn::Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() && !input.peek(Token![,]) {
+        tokens.extend(Some(input.parse::<proc_macro2::TokenTree>()?));
+    }
+
+    Ok(tokens)
+}
+
+/// Wrap delimited contents in parentheses so they parse as [`ValueSyntax::Paren`]
+fn paren(span: Span, contents: TokenStream) -> TokenStream {
+    let mut group = Group::new(Delimiter::Parenthesis, contents);
+    group.set_span(span);
+    group.into_token_stream()
+}
+
+/// Iterate over metadata for the `ParseOption` derive macro
+#[inline]
+pub fn iterate_option_meta(
+    parse: ParseStream<'_>,
+) -> syn::Result<impl Iterator<Item = syn::Result<MetaValueTuple>> + '_> {
+    iterate_option_meta_in(parse, ValueSyntax::DEFAULT)
+}
+
+/// [`iterate_option_meta`] for structs accepting the given value syntaxes
+pub fn iterate_option_meta_in<'a>(
+    parse: ParseStream<'a>,
+    accepted: &'static [ValueSyntax],
+) -> syn::Result<impl Iterator<Item = syn::Result<MetaValueTuple>> + 'a> {
+    let parse_from: DelimitedIter<OptionMeta, Token![,]> = match option_meta_buffer(parse)? {
+        Some(buffer) => buffer.into(),
+        None => parse.into(),
+    };
+
+    Ok(check_option_meta(parse_from, accepted))
+}
+
+/// [`iterate_option_meta_in`] for structs with `#[attr_opts(positional)]` fields. Leading values
+/// that aren't keyed options get passed to `positional` along with their index first.
+pub fn iterate_option_meta_positional<'a>(
+    parse: ParseStream<'a>,
+    accepted: &'static [ValueSyntax],
+    labels: &[&str],
+    positional: &mut dyn FnMut(usize, ParseStream) -> syn::Result<()>,
+) -> syn::Result<impl Iterator<Item = syn::Result<MetaValueTuple>> + 'a> {
+    let parse_from: DelimitedIter<KeyedOptionMeta, Token![,]> =
+        if let Some(buffer) = option_meta_buffer(parse)? {
+            parse_positionals(&buffer, labels, positional)?;
+            buffer.into()
+        } else {
+            parse_positionals(parse, labels, positional)?;
             parse.into()
         };
 
-    Ok(parse_from.map(move |meta| meta.and_then(map_meta)))
+    Ok(check_option_meta(parse_from, accepted))
+}
+
+/// The delimited contents of a `ParseOption` derive's value, if any
+fn option_meta_buffer(parse: ParseStream) -> syn::Result<Option<ParseBuffer>> {
+    match ValueSyntax::from_stream(parse) {
+        Some(syntax) => syntax.parse_token(parse),
+        None => Ok(None),
+    }
+}
+
+/// Check the options against the `accepted` syntaxes & pass [nested](OptionMeta) ones on as
+/// `key(rest, ...)` once the rest have been iterated
+fn check_option_meta<'a, T>(
+    mut options: DelimitedIter<'a, T, Token![,]>,
+    accepted: &'static [ValueSyntax],
+) -> impl Iterator<Item = syn::Result<MetaValueTuple>> + 'a
+where
+    T: Parse + Into<OptionMeta> + 'a,
+{
+    let mut nested = NestedKeys::default();
+    iter::from_fn(move || loop {
+        let meta: OptionMeta = match options.next() {
+            Some(Ok(meta)) => meta.into(),
+            Some(Err(e)) => return Some(Err(e)),
+            None => {
+                let (ident, tokens) = nested.pop_front()?;
+                return Some(Ok((ident, Some(MetaValue::Stream(tokens)))));
+            }
+        };
+
+        if meta.nested {
+            if let (ident, Some(MetaValue::Stream(rest))) = meta.meta {
+                nested.push(ident, rest);
+            }
+            continue;
+        }
+
+        return Some(match meta.syntax {
+            Some((syntax, span)) if !accepted.contains(&syntax) => {
+                Err(syntax.unsupported_error(span, accepted))
+            }
+            _ => Ok(meta.meta),
+        });
+    })
+}
+
+/// An [`OptionMeta`] following the positional values
+struct KeyedOptionMeta(OptionMeta);
+
+impl From<KeyedOptionMeta> for OptionMeta {
+    #[inline]
+    fn from(meta: KeyedOptionMeta) -> Self {
+        meta.0
+    }
+}
+
+impl Parse for KeyedOptionMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![::]) || input.peek(Ident::peek_any) {
+            input.parse().map(Self)
+        } else {
+            Err(positional_after_keyed(input))
+        }
+    }
+}
+
+/// Error if the option following the one just parsed is a positional value
+fn ensure_keyed_next(input: ParseStream) -> syn::Result<()> {
+    let fork = input.fork();
+    if fork.parse::<Option<Token![,]>>()?.is_none()
+        || fork.is_empty()
+        || fork.peek(Token![::])
+        || fork.peek(Ident::peek_any)
+    {
+        Ok(())
+    } else {
+        Err(positional_after_keyed(&fork))
+    }
+}
+
+fn positional_after_keyed(input: ParseStream) -> syn::Error {
+    input.error("Positional arguments must come before keyed options")
+}
+
+/// Whether the input starts with a keyed option rather than a positional value, going by what
+/// follows the leading identifier:
+///
+/// - any `ident = value`
+/// - one of the `labels` followed by a delimited value, `: value`, `,` or the end of the input
+///
+/// A bare label is therefore always a key: a positional identifier sharing a field's label, e.g.
+/// `guard` in `#[get(guard, guard = auth)]`, reads as the bare `guard` option. Expressions
+/// starting with a label, such as `per * 2` or `guard::auth`, remain positional.
+fn peek_key(input: ParseStream, labels: &[&str]) -> bool {
+    let fork = input.fork();
+    let Ok(ident) = fork.call(Ident::parse_any) else {
+        return false;
+    };
+
+    if fork.peek(Token![=]) && !fork.peek(Token![==]) {
+        return true;
+    }
+
+    labels.contains(&ident.unraw().to_string().as_str())
+        && (fork.is_empty()
+            || fork.peek(Token![,])
+            || fork.peek(token::Paren)
+            || fork.peek(token::Bracket)
+            || fork.peek(token::Brace)
+            || (fork.peek(Token![:]) && !fork.peek(Token![::])))
+}
+
+/// Parse the leading values that aren't [keyed](peek_key) along with their commas
+fn parse_positionals(
+    input: ParseStream,
+    labels: &[&str],
+    positional: &mut dyn FnMut(usize, ParseStream) -> syn::Result<()>,
+) -> syn::Result<()> {
+    let mut idx = 0;
+    while !input.is_empty() && !peek_key(input, labels) {
+        positional(idx, input)?;
+        idx += 1;
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// An option passed to [`parse_option_input`]'s logic
+pub enum OptionInput<'a> {
+    /// The value at the given index of the leading values that aren't keyed
+    Positional(usize, ParseStream<'a>),
+
+    /// A keyed option
+    Keyed(ParseNestedMeta<'a>),
+
+    /// The value of a `#[my = value]` attribute, starting with the `=`
+    NameValue(&'a MetaNameValue, ParseStream<'a>),
+}
+
+/// An option passed to [`parse_keyed_input`]'s logic
+pub enum KeyedInput<'a> {
+    /// A keyed option
+    Keyed(ParseNestedMeta<'a>),
+
+    /// The value of a `#[my = value]` attribute, starting with the `=`
+    NameValue(&'a MetaNameValue, ParseStream<'a>),
+}
+
+/// The tokens of a list attribute. A `#[my = value]` attribute's value gets handed to
+/// `name_value` instead.
+fn list_tokens<S, F>(source: S, name_value: F) -> syn::Result<Option<TokenStream>>
+where
+    S: NestedMetaSource,
+    F: FnOnce(&MetaNameValue, ParseStream) -> syn::Result<()>,
+{
+    match source.into_attribute().meta {
+        Meta::List(list) => Ok(Some(list.tokens)),
+        Meta::Path(_) => Ok(None),
+        Meta::NameValue(ref meta) => {
+            let mut tokens = meta.eq_token.into_token_stream();
+            meta.value.to_tokens(&mut tokens);
+
+            let parser = move |input: ParseStream| name_value(meta, input);
+            parser.parse2(tokens).map(|()| None)
+        }
+    }
+}
+
+/// [`NestedMetaSource::parse_nested_meta`] for structs with `#[attr_opts(positional)]` fields.
+/// Leading values that aren't [keyed](peek_key) are positional.
+pub fn parse_option_input<S, F>(
+    source: S,
+    positional_labels: &[&str],
+    mut logic: F,
+) -> syn::Result<()>
+where
+    S: NestedMetaSource,
+    F: FnMut(OptionInput) -> syn::Result<()>,
+{
+    let name_value =
+        |meta: &MetaNameValue, input: ParseStream| logic(OptionInput::NameValue(meta, input));
+    let Some(tokens) = list_tokens(source, name_value)? else {
+        return Ok(());
+    };
+
+    let parser = |input: ParseStream| {
+        parse_positionals(input, positional_labels, &mut |idx, input| {
+            logic(OptionInput::Positional(idx, input))
+        })?;
+
+        let keyed: TokenStream = input.parse()?;
+        parse_keyed(keyed, true, |meta| logic(OptionInput::Keyed(meta)))
+    };
+
+    parser.parse2(tokens)
+}
+
+/// [`NestedMetaSource::parse_nested_meta`] for structs with an
+/// `#[attr_opts(from_name_value)]` field but no positional ones
+pub fn parse_keyed_input<S, F>(source: S, mut logic: F) -> syn::Result<()>
+where
+    S: NestedMetaSource,
+    F: FnMut(KeyedInput) -> syn::Result<()>,
+{
+    let name_value =
+        |meta: &MetaNameValue, input: ParseStream| logic(KeyedInput::NameValue(meta, input));
+    match list_tokens(source, name_value)? {
+        Some(tokens) => parse_keyed(tokens, false, |meta| logic(KeyedInput::Keyed(meta))),
+        None => Ok(()),
+    }
+}
+
+/// The error for a `#[my = value]` attribute on a struct that doesn't accept one
+#[must_use]
+pub fn expected_list(meta: &MetaNameValue) -> syn::Error {
+    syn::Error::new_spanned(
+        meta,
+        format!(
+            "Expected a list, e.g. `{}(...)`",
+            meta.path.to_token_stream().to_string().replace(' ', "")
+        ),
+    )
+}
+
+/// Run `f` over an option's value, checking it against the `accepted` syntaxes. `key: value` gets
+/// passed on as `key = value` and `key[value]` & `key { value }` as `key(value)`.
+pub fn with_value_syntax<R>(
+    stream: ParseStream,
+    accepted: &[ValueSyntax],
+    f: impl FnOnce(ParseStream) -> syn::Result<R>,
+) -> syn::Result<R> {
+    let rewritten = match ValueSyntax::from_stream_in(stream, accepted)? {
+        Some(ValueSyntax::Colon) => {
+            let colon = stream.parse::<Token![:]>()?;
+            let mut tokens = syn::token::Eq(colon.span).into_token_stream();
+            tokens.extend(take_value(stream)?);
+            tokens
+        }
+        Some(syntax @ (ValueSyntax::Bracket | ValueSyntax::Brace)) => {
+            paren(stream.span(), syntax.parse(stream)?)
+        }
+        Some(ValueSyntax::Eq | ValueSyntax::Paren) | None => return f(stream),
+    };
+
+    f.parse2(rewritten)
 }
 
 macro_rules! check_option {
@@ -63,23 +639,45 @@ macro_rules! check_option {
 }
 
 /// Decode a field while iterating attributes
+#[inline]
 pub fn decode_parse_option_field<O: ParseOption + FromExpr>(
     option: &mut Option<O>,
     ident: Ident,
     value_source: Option<MetaValue>,
+) -> syn::Result<()> {
+    decode_parse_option_field_with(option, ident, value_source, false)
+}
+
+/// [`decode_parse_option_field`] for `#[attr_opts(lenient)]` fields
+#[inline]
+pub fn decode_parse_option_field_lenient<O: ParseOption + FromExpr>(
+    option: &mut Option<O>,
+    ident: Ident,
+    value_source: Option<MetaValue>,
+) -> syn::Result<()> {
+    decode_parse_option_field_with(option, ident, value_source, true)
+}
+
+fn decode_parse_option_field_with<O: ParseOption + FromExpr>(
+    option: &mut Option<O>,
+    ident: Ident,
+    value_source: Option<MetaValue>,
+    lenient: bool,
 ) -> syn::Result<()> {
     check_option!(option, ident);
 
-    let new_value = if let Some(meta_value) = value_source {
-        match meta_value {
-            MetaValue::Expr(expr) => O::from_expr(expr)?,
-            MetaValue::Stream(stream) => ParseWrapper::<O>::parse_stream_self(stream)?,
+    let new_value = OptionCtx::new(ident.span(), lenient).scope(move || {
+        if let Some(meta_value) = value_source {
+            match meta_value {
+                MetaValue::Expr(expr) => O::from_expr(expr),
+                MetaValue::Stream(stream) => ParseWrapper::<O>::parse_stream_self(stream),
+            }
+        } else if let Some(v) = O::boolean() {
+            Ok(v)
+        } else {
+            Err(syn::Error::new_spanned(ident, "expected a value"))
         }
-    } else if let Some(v) = O::boolean() {
-        v
-    } else {
-        return Err(syn::Error::new_spanned(ident, "expected a value"));
-    };
+    })?;
 
     *option = Some(new_value);
 
@@ -87,6 +685,7 @@ pub fn decode_parse_option_field<O: ParseOption + FromExpr>(
 }
 
 /// Decode a field while iterating attributes
+#[inline]
 pub fn decode_attr_options_field<O>(
     option: &mut Option<O>,
     source: &impl Spanned,
@@ -95,9 +694,49 @@ pub fn decode_attr_options_field<O>(
 where
     O: ParseOption,
 {
-    check_option!(option, source);
+    decode_attr_options_field_with(option, source.span(), stream, false)
+}
 
-    *option = Some(O::from_stream(stream)?);
+/// [`decode_attr_options_field`] for `#[attr_opts(lenient)]` fields
+#[inline]
+pub fn decode_attr_options_field_lenient<O>(
+    option: &mut Option<O>,
+    source: &impl Spanned,
+    stream: ParseStream,
+) -> syn::Result<()>
+where
+    O: ParseOption,
+{
+    decode_attr_options_field_with(option, source.span(), stream, true)
+}
+
+/// Decode an `#[attr_opts(positional)]` field from its position
+#[inline]
+pub fn decode_positional_field<O: ParseOption>(
+    option: &mut Option<O>,
+    stream: ParseStream,
+) -> syn::Result<()> {
+    decode_attr_options_field_with(option, stream.span(), stream, false)
+}
+
+/// [`decode_positional_field`] for `#[attr_opts(lenient)]` fields
+#[inline]
+pub fn decode_positional_field_lenient<O: ParseOption>(
+    option: &mut Option<O>,
+    stream: ParseStream,
+) -> syn::Result<()> {
+    decode_attr_options_field_with(option, stream.span(), stream, true)
+}
+
+fn decode_attr_options_field_with<O: ParseOption>(
+    option: &mut Option<O>,
+    key: Span,
+    stream: ParseStream,
+    lenient: bool,
+) -> syn::Result<()> {
+    check_option!(option, key);
+
+    *option = Some(OptionCtx::new(key, lenient).scope(move || O::from_stream(stream))?);
     Ok(())
 }
 
@@ -105,3 +744,78 @@ where
 pub fn decode_parse_option_from_parse<O: Parse>(stream: ParseStream) -> syn::Result<O> {
     ValueSyntax::from_stream(stream).and_parse(stream)
 }
+
+/// Decode a [`ParseOption`] using the struct's [`AttributeOptions`] implementation
+///
+/// | Value             | Result |
+/// | ----------------- | ------- |
+/// | `key`             | All options omitted |
+/// | `key(opt1, opt2)` | [`AttributeOptions::from_stream`] |
+/// | `key = expr`      | [`FromExpr::from_expr`] |
+/// | `key anything`    | Error |
+pub fn decode_parse_option_from_attr_options<O>(stream: ParseStream) -> syn::Result<O>
+where
+    O: AttributeOptions + FromExpr,
+{
+    match ValueSyntax::from_stream(stream) {
+        Some(syntax) => match syntax.parse_token(stream)? {
+            Some(content) => <O as AttributeOptions>::from_stream(&content),
+            None => O::from_expr(stream.parse()?),
+        },
+        None if stream.is_empty() || stream.peek(Token![,]) => O::from_args(TokenStream::new()),
+        None => Err(stream.error("Expected `= value`, `(...)` or `,` after the key")),
+    }
+}
+
+/// Decode a [`FromExpr`] with the `from_parse` option set
+pub fn decode_from_expr_from_parse<O: Parse>(expr: syn::Expr) -> syn::Result<O> {
+    syn::parse2(expr.into_token_stream())
+}
+
+/// Decode a derived [`ParseOption`] from a struct literal: `Foo { opt1: val1, opt2 }`
+pub fn decode_parse_option_from_expr<O: ParseOption>(expr: syn::Expr) -> syn::Result<O> {
+    ParseWrapper::<O>::parse_stream_self(struct_literal_options(expr)?)
+}
+
+/// [`decode_parse_option_from_expr`] for `AttributeOptions` with the `parse_option` option set
+pub fn decode_attr_options_from_expr<O: AttributeOptions>(expr: syn::Expr) -> syn::Result<O> {
+    O::from_args(struct_literal_options(expr)?)
+}
+
+/// Turn a struct literal into options: `Foo { opt1: val1, opt2 }` -> `opt1 = val1, opt2`
+fn struct_literal_options(expr: syn::Expr) -> syn::Result<TokenStream> {
+    let syn::Expr::Struct(expr) = expr else {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "Expected a struct literal, e.g. `Foo { bar: 1 }`",
+        ));
+    };
+
+    if let Some(rest) = expr.rest {
+        return Err(syn::Error::new_spanned(
+            rest,
+            "Struct update syntax not supported",
+        ));
+    }
+
+    let mut tokens = TokenStream::new();
+    for pair in expr.fields.into_pairs() {
+        let (field, comma) = pair.into_tuple();
+        match field.member {
+            Member::Named(ident) => ident.to_tokens(&mut tokens),
+            Member::Unnamed(index) => {
+                return Err(syn::Error::new_spanned(index, "expected ident"));
+            }
+        }
+
+        if let Some(colon) = field.colon_token {
+            syn::token::Eq(colon.span).to_tokens(&mut tokens);
+            field.expr.to_tokens(&mut tokens);
+        }
+
+        comma.to_tokens(&mut tokens);
+    }
+
+    Ok(tokens)
+}
+
@@ -0,0 +1,81 @@
+/// Labels of the options that were explicitly provided when parsing.
+///
+/// Add a field of this type with the `#[attr_opts(presence)]` option to a derived
+/// [`AttributeOptions`](crate::AttributeOptions) or [`ParseOption`](crate::ParseOption) struct to
+/// have it populated. Labels are recorded after renaming.
+///
+/// # Example
+///
+/// ```
+/// # use macroific_attr_parse::Presence;
+/// #
+/// let mut presence = Presence::new();
+/// assert!(presence.insert("foo"));
+/// assert!(!presence.insert("foo"));
+///
+/// assert!(presence.is_set("foo"));
+/// assert!(!presence.is_set("bar"));
+/// assert_eq!(presence.iter().collect::<Vec<_>>(), ["foo"]);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Presence(Vec<&'static str>);
+
+impl Presence {
+    /// Create an empty set
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Mark the option as provided. Returns `false` if it had already been marked.
+    pub fn insert(&mut self, label: &'static str) -> bool {
+        if self.is_set(label) {
+            false
+        } else {
+            self.0.push(label);
+            true
+        }
+    }
+
+    /// Check whether the option with the given label was provided
+    #[must_use]
+    pub fn is_set(&self, label: &str) -> bool {
+        self.0.contains(&label)
+    }
+
+    /// Iterate over the provided options' labels in field order
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Number of provided options
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// `true` if no options were provided
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Extend<&'static str> for Presence {
+    fn extend<T: IntoIterator<Item = &'static str>>(&mut self, iter: T) {
+        for label in iter {
+            self.insert(label);
+        }
+    }
+}
+
+impl FromIterator<&'static str> for Presence {
+    fn from_iter<T: IntoIterator<Item = &'static str>>(iter: T) -> Self {
+        let mut out = Self::new();
+        out.extend(iter);
+        out
+    }
+}
@@ -0,0 +1,199 @@
+//! Sequence option support: `key(a, b)`, `key = [a, b]` and `key = (a, b)`.
+
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::parse::{ParseBuffer, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{bracketed, parenthesized, token, Error, Expr, Result, Token};
+
+use crate::__attr_parse_prelude::*;
+use crate::ungroup::ungroup;
+use crate::ValueSyntax;
+
+const EXPECTED_LIST: &str = "Expected a list, e.g. `key(a, b)` or `key = [a, b]`";
+
+/// Contents of a sequence option being parsed one element at a time
+pub(crate) struct Seq<'a> {
+    content: ParseBuffer<'a>,
+    span: Span,
+    found: usize,
+}
+
+impl<'a> Seq<'a> {
+    /// Parse the sequence's delimiters. Bare `[a, b]` & `(a, b)` are accepted for sequences
+    /// nested within other sequences.
+    pub fn parse(input: ParseStream<'a>) -> Result<Self> {
+        let (span, content) = parse_delimited(input)?;
+
+        Ok(Self {
+            content,
+            span,
+            found: 0,
+        })
+    }
+
+    /// Parse the next element along with its span, `None` if the sequence has been exhausted
+    pub fn next<T: ParseOption>(&mut self) -> Result<Option<(Span, T)>> {
+        if self.content.is_empty() {
+            return Ok(None);
+        }
+
+        let span = self.content.span();
+        let value = T::from_stream(&self.content)?;
+        if !self.content.is_empty() {
+            self.content.parse::<Token![,]>()?;
+        }
+        self.found += 1;
+
+        Ok(Some((span, value)))
+    }
+
+    /// Parse the next element of a sequence that should contain exactly `expected` elements
+    pub fn next_required<T: ParseOption>(&mut self, expected: usize) -> Result<T> {
+        match self.next()? {
+            Some((_, value)) => Ok(value),
+            None => Err(count_error(self.span, expected, self.found)),
+        }
+    }
+
+    /// Ensure there are no elements past the `expected` count
+    pub fn finish(self, expected: usize) -> Result<()> {
+        if self.content.is_empty() {
+            Ok(())
+        } else {
+            let msg = format!("Unexpected element: expected {expected} elements");
+            Err(Error::new(self.content.span(), msg))
+        }
+    }
+}
+
+/// Parse an optional `=` followed by a bracketed or parenthesised group, returning the group's
+/// span & contents
+pub(crate) fn parse_delimited(input: ParseStream) -> Result<(Span, ParseBuffer)> {
+    if let Some(ValueSyntax::Eq) = ValueSyntax::from_stream(input) {
+        input.parse::<Token![=]>()?;
+    }
+
+    let span = input.span();
+    let content;
+    if input.peek(token::Bracket) {
+        bracketed!(content in input);
+    } else if input.peek(token::Paren) {
+        parenthesized!(content in input);
+    } else {
+        return Err(input.error(EXPECTED_LIST));
+    }
+
+    Ok((span, content))
+}
+
+/// Elements of a sequence option along with their spans
+pub(crate) struct Elements<T> {
+    span: Span,
+    items: Vec<(Span, T)>,
+}
+
+impl<T> Elements<T> {
+    /// Parse the elements of a `key(a, b)`, `key = [a, b]` or `key = (a, b)` option
+    pub fn from_stream(input: ParseStream) -> Result<Self>
+    where
+        T: ParseOption,
+    {
+        let mut seq = Seq::parse(input)?;
+        let mut items = Vec::new();
+        while let Some(item) = seq.next()? {
+            items.push(item);
+        }
+
+        Ok(Self {
+            span: seq.span,
+            items,
+        })
+    }
+
+    /// Convert the elements of an array or tuple expression
+    pub fn from_expr(expr: Expr) -> Result<Self>
+    where
+        T: FromExpr,
+    {
+        let (span, exprs) = split_expr(expr)?;
+        let items = exprs
+            .into_iter()
+            .map(move |expr| {
+                let span = expr.span();
+                T::from_expr(expr).map(move |value| (span, value))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { span, items })
+    }
+
+    /// Iterate over the element values
+    pub fn values(self) -> impl Iterator<Item = T> {
+        self.items.into_iter().map(move |(_, value)| value)
+    }
+
+    /// Collect into a set, erroring on the first element `insert` rejects
+    pub fn collect_unique<C: Default>(
+        self,
+        mut insert: impl FnMut(&mut C, T) -> bool,
+    ) -> Result<C> {
+        let mut out = C::default();
+        for (span, value) in self.items {
+            if !insert(&mut out, value) {
+                return Err(Error::new(span, "Duplicate element"));
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Collect into an array of exactly `N` elements
+    pub fn into_array<const N: usize>(self) -> Result<[T; N]> {
+        let span = self.span;
+        into_array(span, self.values().collect())
+    }
+}
+
+/// Split an array or tuple expression into exactly `N` elements
+pub(crate) fn split_expr_exact<const N: usize>(expr: Expr) -> Result<[Expr; N]> {
+    let (span, exprs) = split_expr(expr)?;
+    into_array(span, exprs)
+}
+
+fn into_array<T, const N: usize>(span: Span, values: Vec<T>) -> Result<[T; N]> {
+    values
+        .try_into()
+        .map_err(move |values: Vec<T>| count_error(span, N, values.len()))
+}
+
+fn count_error(span: Span, expected: usize, found: usize) -> Error {
+    Error::new(span, format!("Expected {expected} elements, found {found}"))
+}
+
+/// Works off tokens rather than [`Expr::Array`] & [`Expr::Tuple`] as those require syn's `full`
+/// feature.
+fn split_expr(expr: Expr) -> Result<(Span, Vec<Expr>)> {
+    let expr = ungroup(expr);
+    let span = expr.span();
+    let parser = move |input: ParseStream| {
+        let content;
+        if input.peek(token::Bracket) {
+            bracketed!(content in input);
+        } else if input.peek(token::Paren) {
+            parenthesized!(content in input);
+        } else {
+            return Err(Error::new(
+                span,
+                "Expected an array or tuple, e.g. `[a, b]`",
+            ));
+        }
+
+        let exprs = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+        Ok(exprs.into_iter().collect())
+    };
+
+    let exprs = parser.parse2(expr.into_token_stream())?;
+    Ok((span, exprs))
+}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+use proc_macro2::Span;
+use syn::parse::ParseStream;
+use syn::Token;
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::OptionCtx;
+use crate::ValueSyntax;
+
+/// An option value that remembers where it came from: the span of the key it was provided under
+/// and the span of the value itself.
+///
+/// Comparisons & hashing only consider the value.
+///
+/// # Example
+///
+/// ```
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, Spanned};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   rename: Option<Spanned<String>>,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(rename = "foo")])).unwrap();
+/// let rename = opts.rename.unwrap();
+///
+/// assert_eq!(*rename, "foo"); // Derefs to the inner value
+///
+/// // Report errors about the option after parsing
+/// let err = syn::Error::new(rename.key_span(), "this `rename` collides with another field");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Spanned<T> {
+    value: T,
+    key_span: Span,
+    value_span: Span,
+}
+
+impl<T> Spanned<T> {
+    /// Wrap the value with the given spans
+    #[inline]
+    pub const fn new(value: T, key_span: Span, value_span: Span) -> Self {
+        Self {
+            value,
+            key_span,
+            value_span,
+        }
+    }
+
+    /// Span of the option's key, e.g. `foo` in `foo = "bar"`. Defaults to
+    /// [`Span::call_site`] if the value wasn't parsed under a key.
+    #[inline]
+    #[must_use]
+    pub const fn key_span(&self) -> Span {
+        self.key_span
+    }
+
+    /// Span of the option's value, e.g. `"bar"` in `foo = "bar"`. Same as the
+    /// [key span](Self::key_span) if the value was implied, e.g. a bare `foo` boolean.
+    #[inline]
+    #[must_use]
+    pub const fn value_span(&self) -> Span {
+        self.value_span
+    }
+
+    /// Get the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Map the inner value, keeping the spans
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Spanned<U> {
+        Spanned::new(f(self.value), self.key_span, self.value_span)
+    }
+}
+
+impl<T: ParseOption> ParseOption for Spanned<T> {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        let key_span = OptionCtx::current().key;
+        let value_span = value_span(key_span, input)?;
+        Ok(Self::new(T::from_stream(input)?, key_span, value_span))
+    }
+}
+
+/// Span of the value about to be parsed from the stream, or the key's if it's implied
+fn value_span(key: Span, input: ParseStream) -> syn::Result<Span> {
+    Ok(match ValueSyntax::from_stream(input) {
+        Some(syntax) if !syntax.is_delimited() => {
+            let fork = input.fork();
+            syntax.parse_token(&fork)?;
+            fork.span()
+        }
+        None if input.is_empty() || input.peek(Token![,]) => key,
+        Some(_) | None => input.span(),
+    })
+}
+
+impl<T: FromExpr> FromExpr for Spanned<T> {
+    fn from_expr(expr: syn::Expr) -> syn::Result<Self> {
+        let value_span = syn::spanned::Spanned::span(&expr);
+        Ok(Self::new(
+            T::from_expr(expr)?,
+            OptionCtx::current().key,
+            value_span,
+        ))
+    }
+
+    fn boolean() -> Option<Self> {
+        let key_span = OptionCtx::current().key;
+        Some(Self::new(T::boolean()?, key_span, key_span))
+    }
+}
+
+impl<T: Default> Default for Spanned<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default(), Span::call_site(), Span::call_site())
+    }
+}
+
+impl<T> Deref for Spanned<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Spanned<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Spanned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spanned")
+            .field("value", &self.value)
+            .field("key_span", &self.key_span)
+            .field("value_span", &self.value_span)
+            .finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Spanned<T> {}
+
+impl<T: PartialOrd> PartialOrd for Spanned<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord> Ord for Spanned<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash> Hash for Spanned<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
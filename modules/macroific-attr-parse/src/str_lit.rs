@@ -0,0 +1,202 @@
+use std::ops::{Deref, DerefMut};
+
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Expr, ExprLit, ExprPath, Lit, LitStr, Path, Type, WherePredicate};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::expecting;
+use crate::ungroup::ungroup;
+use crate::{lenient, ValueSyntax};
+
+/// An option that's provided as a string literal and parsed as `T`, akin to serde's
+/// `bound = "..."`. Useful for syntax that doesn't parse as an expression, e.g. where predicates
+/// containing commas.
+///
+/// The parsed tokens are spanned to the string literal.
+///
+/// # Example
+///
+/// ```
+/// # use quote::ToTokens;
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, FromStrLit};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   bound: Option<FromStrLit<syn::WhereClause>>,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(bound = "where T: Clone, U: Default")]))
+///   .unwrap();
+/// let bound = opts.bound.unwrap().into_inner();
+///
+/// assert_eq!(bound.predicates.len(), 2);
+/// assert_eq!(bound.predicates[0].to_token_stream().to_string(), "T : Clone");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct FromStrLit<T>(T);
+
+impl<T> FromStrLit<T> {
+    #[allow(missing_docs)]
+    #[inline]
+    pub const fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    /// Get the inner value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Parse> ParseOption for FromStrLit<T> {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        expecting("string");
+        let lit = ValueSyntax::from_stream(input).and_parse::<LitStr>(input)?;
+        lit.parse().map(Self)
+    }
+}
+
+impl<T: Parse> FromExpr for FromStrLit<T> {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        expecting("string");
+        LitStr::from_expr(expr)?.parse().map(Self)
+    }
+}
+
+impl<T> Deref for FromStrLit<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for FromStrLit<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Types that accept a string literal in place of their regular syntax
+trait StrLitAlt: Parse {
+    fn parse_str_lit(lit: &LitStr) -> syn::Result<Self> {
+        lit.parse()
+    }
+
+    /// Regular [`FromExpr`] conversion for when the expression isn't a string literal
+    fn from_non_str_expr(expr: Expr) -> syn::Result<Self> {
+        syn::parse2(expr.into_token_stream())
+    }
+}
+
+impl StrLitAlt for Type {}
+impl StrLitAlt for WherePredicate {}
+
+impl StrLitAlt for Path {
+    fn from_non_str_expr(expr: Expr) -> syn::Result<Self> {
+        Ok(ExprPath::from_expr(expr)?.path)
+    }
+}
+
+impl StrLitAlt for Ident {
+    /// Keywords are accepted within string literals, e.g. `"type"`
+    fn parse_str_lit(lit: &LitStr) -> syn::Result<Self> {
+        lit.parse_with(Ident::parse_any)
+    }
+}
+
+/// [`Parse`]s either the regular syntax or a string literal
+struct OrStrLit<T>(T);
+
+/// [`OrStrLit`] for lenient identifiers, which may also be keywords, e.g. `type` or `true`
+struct AnyIdentOrStrLit(Ident);
+
+impl Parse for AnyIdentOrStrLit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Ident::parse_str_lit(&input.parse()?).map(Self)
+        } else {
+            Ident::parse_any(input).map(Self)
+        }
+    }
+}
+
+impl<T: StrLitAlt> Parse for OrStrLit<T> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            T::parse_str_lit(&input.parse()?).map(Self)
+        } else {
+            input.parse().map(Self)
+        }
+    }
+}
+
+macro_rules! impl_str_lit_alt {
+    ($([$ty: ty, $name: literal]),+) => {
+        $(
+            impl ParseOption for $ty {
+                fn from_stream(input: ParseStream) -> syn::Result<Self> {
+                    expecting($name);
+                    let OrStrLit(out) = ValueSyntax::from_stream(input).and_parse(input)?;
+                    Ok(out)
+                }
+            }
+
+            impl FromExpr for $ty {
+                fn from_expr(expr: Expr) -> syn::Result<Self> {
+                    expecting($name);
+                    let expr = ungroup(expr);
+                    if let Expr::Lit(ExprLit { lit: Lit::Str(ref lit), .. }) = expr {
+                        <$ty>::parse_str_lit(lit)
+                    } else {
+                        <$ty>::from_non_str_expr(expr)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_str_lit_alt!(
+    [Type, "type"],
+    [Path, "path"],
+    [WherePredicate, "where predicate"]
+);
+
+impl ParseOption for Ident {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        expecting("identifier");
+        let syntax = ValueSyntax::from_stream(input);
+        if lenient::enabled() {
+            let AnyIdentOrStrLit(out) = syntax.and_parse(input)?;
+            Ok(out)
+        } else {
+            let OrStrLit(out) = syntax.and_parse(input)?;
+            Ok(out)
+        }
+    }
+}
+
+impl FromExpr for Ident {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        expecting("identifier");
+        let expr = ungroup(expr);
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(ref lit),
+                ..
+            }) => Self::parse_str_lit(lit),
+            // `true` & `false` parse as boolean literals
+            expr if lenient::enabled() => Ident::parse_any.parse2(expr.into_token_stream()),
+            expr => Self::from_non_str_expr(expr),
+        }
+    }
+}
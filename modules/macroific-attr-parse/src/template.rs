@@ -0,0 +1,245 @@
+use std::fmt::{self, Display, Write as _};
+use std::ops::Range;
+
+use proc_macro2::{Ident, Span};
+use syn::parse::ParseStream;
+use syn::{Error, Expr, LitStr};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::expecting;
+use crate::ValueSyntax;
+
+/// A string literal option with `{placeholder}` references, e.g. `name = "get_{field}"`.
+///
+/// `{{` and `}}` escape literal braces. Errors about specific placeholders point inside the string
+/// literal where the compiler supports it and at the whole literal otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use proc_macro2::Span;
+/// # use syn::parse_quote;
+/// use macroific::attr_parse::{prelude::*, Template};
+///
+/// #[derive(AttributeOptions)]
+/// struct MyOptions {
+///   name: Option<Template>,
+/// }
+///
+/// let opts = MyOptions::from_attr(parse_quote!(#[my(name = "get_{field}")])).unwrap();
+/// let name = opts.name.unwrap();
+/// name.validate(&["field", "ty"]).unwrap();
+///
+/// let ident = name.render_ident(Span::call_site(), |p| (p == "field").then_some("foo")).unwrap();
+/// assert_eq!(ident, "get_foo");
+///
+/// let err = name.validate(&["ty"]).unwrap_err();
+/// assert_eq!(err.to_string(), "Unknown placeholder `field`, expected one of: `ty`");
+///
+/// let err = MyOptions::from_attr(parse_quote!(#[my(name = "get_{field")])).err().unwrap();
+/// assert_eq!(err.to_string(), "Unclosed placeholder; use `{{` for a literal `{`");
+/// ```
+#[derive(Clone)]
+pub struct Template {
+    lit: LitStr,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone)]
+enum Part {
+    Text(String),
+    Placeholder { name: String, range: Range<usize> },
+}
+
+impl Template {
+    /// Parse the template from a string literal
+    pub fn from_lit(lit: LitStr) -> syn::Result<Self> {
+        let value = lit.value();
+        match parse_parts(&value) {
+            Ok(parts) => Ok(Self { lit, parts }),
+            Err((range, msg)) => Err(Error::new(subspan(&lit, &value, range), msg)),
+        }
+    }
+
+    /// The string literal the template was parsed from
+    #[inline]
+    #[must_use]
+    pub fn lit(&self) -> &LitStr {
+        &self.lit
+    }
+
+    /// Names of the referenced placeholders in order of appearance
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(move |part| match *part {
+            Part::Placeholder { ref name, .. } => Some(name.as_str()),
+            Part::Text(_) => None,
+        })
+    }
+
+    /// Ensure the template only references placeholders in the `allowed` set. Every unknown
+    /// placeholder gets reported.
+    pub fn validate(&self, allowed: &[&str]) -> syn::Result<()> {
+        let mut suffix = String::from(", expected one of: ");
+        for (idx, name) in allowed.iter().enumerate() {
+            if idx != 0 {
+                suffix.push_str(", ");
+            }
+            let _ = write!(suffix, "`{name}`");
+        }
+
+        let mut out: Option<Error> = None;
+        for part in &self.parts {
+            if let Part::Placeholder {
+                ref name,
+                ref range,
+            } = *part
+            {
+                if !allowed.contains(&name.as_str()) {
+                    let err = self.unknown_placeholder(name, range.clone(), &suffix);
+                    match out {
+                        Some(ref mut out) => out.combine(err),
+                        None => out = Some(err),
+                    }
+                }
+            }
+        }
+
+        out.map_or(Ok(()), Err)
+    }
+
+    /// Render the template, substituting placeholders with whatever `lookup` returns for them.
+    /// Errors on placeholders `lookup` returns `None` for.
+    pub fn render<F, D>(&self, mut lookup: F) -> syn::Result<String>
+    where
+        F: FnMut(&str) -> Option<D>,
+        D: Display,
+    {
+        let mut out = String::new();
+        for part in &self.parts {
+            match *part {
+                Part::Text(ref text) => out.push_str(text),
+                Part::Placeholder {
+                    ref name,
+                    ref range,
+                } => match lookup(name) {
+                    Some(value) => {
+                        let _ = write!(out, "{value}");
+                    }
+                    None => return Err(self.unknown_placeholder(name, range.clone(), "")),
+                },
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// [Render](Self::render) the template as an identifier with the given span
+    pub fn render_ident<F, D>(&self, span: Span, lookup: F) -> syn::Result<Ident>
+    where
+        F: FnMut(&str) -> Option<D>,
+        D: Display,
+    {
+        let rendered = self.render(lookup)?;
+        match syn::parse_str::<Ident>(&rendered) {
+            Ok(mut ident) => {
+                ident.set_span(span);
+                Ok(ident)
+            }
+            Err(_) => Err(Error::new(
+                self.lit.span(),
+                format!("`{rendered}` is not a valid identifier"),
+            )),
+        }
+    }
+
+    fn unknown_placeholder(&self, name: &str, range: Range<usize>, suffix: &str) -> Error {
+        let span = subspan(&self.lit, &self.lit.value(), range);
+        Error::new(span, format!("Unknown placeholder `{name}`{suffix}"))
+    }
+}
+
+impl fmt::Debug for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Template")
+            .field("value", &self.lit.value())
+            .field("parts", &self.parts)
+            .finish()
+    }
+}
+
+/// Span of the given byte range of the literal's value if the compiler supports it and the
+/// literal has no escapes, the whole literal's span otherwise.
+fn subspan(lit: &LitStr, value: &str, range: Range<usize>) -> Span {
+    let token = lit.token();
+    let repr = token.to_string();
+
+    repr.find('"')
+        .map(move |quote| quote + 1)
+        .filter(|&start| repr.get(start..start + value.len()) == Some(value))
+        .and_then(move |start| token.subspan(start + range.start..start + range.end))
+        .unwrap_or_else(move || lit.span())
+}
+
+fn parse_parts(value: &str) -> Result<Vec<Part>, (Range<usize>, &'static str)> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = value.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' | '}' if chars.next_if(move |&(_, next)| next == c).is_some() => text.push(c),
+            '{' => {
+                let mut name = String::new();
+                let end = loop {
+                    match chars.next() {
+                        Some((end, '}')) => break end + 1,
+                        Some((_, c)) => name.push(c),
+                        None => {
+                            let msg = "Unclosed placeholder; use `{{` for a literal `{`";
+                            return Err((start..value.len(), msg));
+                        }
+                    }
+                };
+
+                if name.is_empty() || !name.chars().all(move |c| c.is_alphanumeric() || c == '_') {
+                    return Err((start..end, "Invalid placeholder name"));
+                }
+                if !text.is_empty() {
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                }
+                parts.push(Part::Placeholder {
+                    name,
+                    range: start..end,
+                });
+            }
+            '}' => {
+                return Err((
+                    start..start + 1,
+                    "Unmatched `}`; use `}}` for a literal `}`",
+                ))
+            }
+            c => text.push(c),
+        }
+    }
+
+    if !text.is_empty() {
+        parts.push(Part::Text(text));
+    }
+
+    Ok(parts)
+}
+
+impl ParseOption for Template {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        expecting("string");
+        Self::from_lit(ValueSyntax::from_stream(input).and_parse(input)?)
+    }
+}
+
+impl FromExpr for Template {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        expecting("string");
+        Self::from_lit(LitStr::from_expr(expr)?)
+    }
+}
+
@@ -0,0 +1,23 @@
+//! Invisible group handling for values forwarded by `macro_rules!`, e.g. `$v:expr`.
+
+use syn::{Expr, ExprGroup, ExprParen};
+
+/// Strip invisible groups
+pub(crate) fn ungroup(mut expr: Expr) -> Expr {
+    while let Expr::Group(ExprGroup { expr: inner, .. }) = expr {
+        expr = *inner;
+    }
+
+    expr
+}
+
+/// Strip invisible groups & parentheses, e.g. for literals: `("foo")` → `"foo"`
+pub(crate) fn unparen(mut expr: Expr) -> Expr {
+    loop {
+        match expr {
+            Expr::Group(ExprGroup { expr: inner, .. })
+            | Expr::Paren(ExprParen { expr: inner, .. }) => expr = *inner,
+            expr => return expr,
+        }
+    }
+}
@@ -1,17 +1,43 @@
+use proc_macro2::{Ident, Span};
 use syn::parse::{Parse, ParseBuffer, ParseStream};
-use syn::{parenthesized, Token};
+use syn::{braced, bracketed, parenthesized, token, Error, Expr, ExprPath, Token};
+
+use crate::__attr_parse_prelude::*;
+use crate::option_ctx::expecting;
 
 /// Syntax used for providing a value
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
 pub enum ValueSyntax {
     /// `= contents`
     Eq,
 
     /// `(contents)`
     Paren,
+
+    /// `: contents`
+    Colon,
+
+    /// `[contents]`
+    Bracket,
+
+    /// `{ contents }`
+    Brace,
 }
 
 impl ValueSyntax {
+    /// The syntaxes [`from_stream`](Self::from_stream) recognises
+    pub const DEFAULT: &'static [Self] = &[Self::Eq, Self::Paren];
+
+    /// Every syntax
+    pub const ALL: &'static [Self] = &[
+        Self::Eq,
+        Self::Paren,
+        Self::Colon,
+        Self::Bracket,
+        Self::Brace,
+    ];
+
     /// Returns `true` if the syntax is [`Eq`](ValueSyntax::Eq).
     ///
     /// # Example
@@ -44,8 +70,62 @@ impl ValueSyntax {
         matches!(self, Self::Paren)
     }
 
+    /// Returns `true` if the syntax is [`Colon`](ValueSyntax::Colon).
+    #[inline]
+    #[must_use]
+    pub const fn is_colon(self) -> bool {
+        matches!(self, Self::Colon)
+    }
+
+    /// Returns `true` if the syntax is [`Bracket`](ValueSyntax::Bracket).
+    #[inline]
+    #[must_use]
+    pub const fn is_bracket(self) -> bool {
+        matches!(self, Self::Bracket)
+    }
+
+    /// Returns `true` if the syntax is [`Brace`](ValueSyntax::Brace).
+    #[inline]
+    #[must_use]
+    pub const fn is_brace(self) -> bool {
+        matches!(self, Self::Brace)
+    }
+
+    /// Returns `true` if the contents are delimited, i.e. the syntax is
+    /// [`Paren`](ValueSyntax::Paren), [`Bracket`](ValueSyntax::Bracket) or
+    /// [`Brace`](ValueSyntax::Brace).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macroific_attr_parse::ValueSyntax;
+    /// #
+    /// assert!(ValueSyntax::Bracket.is_delimited());
+    /// assert!(!ValueSyntax::Colon.is_delimited());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_delimited(self) -> bool {
+        matches!(self, Self::Paren | Self::Bracket | Self::Brace)
+    }
+
+    /// How the syntax looks, e.g. `` `= value` ``. Used in error messages.
+    #[must_use]
+    pub const fn describe(self) -> &'static str {
+        match self {
+            Self::Eq => "`= value`",
+            Self::Paren => "`(value)`",
+            Self::Colon => "`: value`",
+            Self::Bracket => "`[value]`",
+            Self::Brace => "`{ value }`",
+        }
+    }
+
     /// Peek the stream **without moving the cursor** and attempt to construct self based on the
-    /// next token.
+    /// next token. Looks through invisible groups, e.g. `macro_rules!`-forwarded fragments.
+    ///
+    /// Only the [default](Self::DEFAULT) syntaxes are recognised as values such as `[u8; 4]` can
+    /// start with a bracket; see [`from_stream_in`](Self::from_stream_in) for the others.
     ///
     /// # Example
     ///
@@ -77,15 +157,93 @@ impl ValueSyntax {
     /// assert_eq!(v.peek, Some(ValueSyntax::Paren));
     /// assert_eq!(v.rest.to_string(), "(456)");
     ///
+    /// let group = proc_macro2::Group::new(proc_macro2::Delimiter::None, quote!(= 789));
+    /// let v: Foo = syn::parse2(quote!(#group)).unwrap();
+    /// assert_eq!(v.peek, Some(ValueSyntax::Eq));
+    ///
     /// let v: Foo = syn::parse2(quote!(none)).unwrap();
     /// assert_eq!(v.peek, None);
     /// assert_eq!(v.rest.to_string(), "none");
     /// ```
     pub fn from_stream(parse: ParseStream) -> Option<Self> {
+        Self::peek(parse).filter(move |syntax| Self::DEFAULT.contains(syntax))
+    }
+
+    /// [`from_stream`](Self::from_stream) variant for use right after a key that recognises
+    /// every syntax, erroring if it isn't in the `accepted` set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macroific_attr_parse::ValueSyntax;
+    /// # use syn::parse::{Parse, ParseStream};
+    /// # use quote::quote;
+    /// #
+    /// struct Foo(syn::LitInt);
+    ///
+    /// impl Parse for Foo {
+    ///   fn parse(input: ParseStream) -> syn::Result<Self> {
+    ///     let syntax = ValueSyntax::from_stream_in(input, &[ValueSyntax::Colon, ValueSyntax::Bracket])?;
+    ///     syntax.expect("no syntax").parse(input).map(Self)
+    ///   }
+    /// }
+    ///
+    /// assert_eq!(syn::parse2::<Foo>(quote!(: 1)).unwrap().0.base10_digits(), "1");
+    /// assert_eq!(syn::parse2::<Foo>(quote!([2])).unwrap().0.base10_digits(), "2");
+    ///
+    /// let err = syn::parse2::<Foo>(quote!(= 3)).err().unwrap();
+    /// assert_eq!(
+    ///   err.to_string(),
+    ///   "Unsupported value syntax `= value`, expected `: value` or `[value]`"
+    /// );
+    /// ```
+    pub fn from_stream_in(parse: ParseStream, accepted: &[Self]) -> syn::Result<Option<Self>> {
+        match Self::peek(parse) {
+            Some(syntax) if !accepted.contains(&syntax) => {
+                Err(syntax.unsupported_error(parse.span(), accepted))
+            }
+            syntax => Ok(syntax),
+        }
+    }
+
+    pub(crate) fn unsupported_error(self, span: Span, accepted: &[Self]) -> Error {
+        let mut expected = String::new();
+        if let Some((last, rest)) = accepted.split_last() {
+            for (idx, syntax) in rest.iter().enumerate() {
+                if idx != 0 {
+                    expected.push_str(", ");
+                }
+                expected.push_str(syntax.describe());
+            }
+            if !rest.is_empty() {
+                expected.push_str(" or ");
+            }
+            expected.push_str(last.describe());
+        }
+
+        let msg = if expected.is_empty() {
+            format!("Unsupported value syntax {}", self.describe())
+        } else {
+            format!(
+                "Unsupported value syntax {}, expected {expected}",
+                self.describe()
+            )
+        };
+        Error::new(span, msg)
+    }
+
+    /// Recognise any syntax
+    pub(crate) fn peek(parse: ParseStream) -> Option<Self> {
         if parse.peek(Token![=]) {
             Some(Self::Eq)
-        } else if parse.peek(syn::token::Paren) {
+        } else if parse.peek(token::Paren) {
             Some(Self::Paren)
+        } else if parse.peek(Token![:]) && !parse.peek(Token![::]) {
+            Some(Self::Colon)
+        } else if parse.peek(token::Bracket) {
+            Some(Self::Bracket)
+        } else if parse.peek(token::Brace) {
+            Some(Self::Brace)
         } else {
             None
         }
@@ -93,7 +251,7 @@ impl ValueSyntax {
 
     /// Parse whatever tokens need to be parsed based on the resolved syntax.
     /// Returns a `ParseBuffer` you should continue parsing if the syntax is
-    /// [`Paren`](ValueSyntax::Paren).
+    /// [delimited](Self::is_delimited).
     ///
     /// # Example
     ///
@@ -133,11 +291,25 @@ impl ValueSyntax {
                 input.parse::<Token![=]>()?;
                 Ok(None)
             }
+            Self::Colon => {
+                input.parse::<Token![:]>()?;
+                Ok(None)
+            }
             Self::Paren => {
                 let content;
                 parenthesized!(content in input);
                 Ok(Some(content))
             }
+            Self::Bracket => {
+                let content;
+                bracketed!(content in input);
+                Ok(Some(content))
+            }
+            Self::Brace => {
+                let content;
+                braced!(content in input);
+                Ok(Some(content))
+            }
         }
     }
 
@@ -181,3 +353,44 @@ impl ValueSyntax {
         }
     }
 }
+
+impl ValueSyntax {
+    fn from_ident(ident: &Ident) -> syn::Result<Self> {
+        Ok(match ident.to_string().as_str() {
+            "eq" => Self::Eq,
+            "paren" => Self::Paren,
+            "colon" => Self::Colon,
+            "bracket" => Self::Bracket,
+            "brace" => Self::Brace,
+            other => {
+                let msg = format!(
+                    "Unknown value syntax `{other}`, expected one of: `eq`, `paren`, `colon`, `bracket`, `brace`"
+                );
+                return Err(Error::new(ident.span(), msg));
+            }
+        })
+    }
+}
+
+/// Parses `eq`, `paren`, `colon`, `bracket` or `brace`
+impl ParseOption for ValueSyntax {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        expecting("value syntax");
+        Self::from_ident(&Self::from_stream(input).and_parse(input)?)
+    }
+}
+
+impl FromExpr for ValueSyntax {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        expecting("value syntax");
+        let path = ExprPath::from_expr(expr)?.path;
+        match path.get_ident() {
+            Some(ident) => Self::from_ident(ident),
+            None => Err(Error::new_spanned(
+                path,
+                "Expected a value syntax, e.g. `eq`",
+            )),
+        }
+    }
+}
+
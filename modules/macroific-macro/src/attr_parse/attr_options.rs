@@ -1,14 +1,58 @@
-use syn::{DeriveInput, Token};
+use proc_macro2::Span;
+use syn::{Attribute, DeriveInput, Token};
 
+use macroific_attr_parse::__private::decode_attr_options_field;
+use macroific_attr_parse::{AttributeOptions, ValueSyntax};
 use macroific_core::core_ext::*;
+use macroific_core::elements::{GenericImpl, ModulePrefix};
 
 use super::{
-    Delimiter, Fields, Generics, Group, Ident, ParseStream, Render, ToTokens, TokenStream, PRIVATE,
-    RESULT,
+    Delimiter, Fields, Generics, Group, Ident, ParseStream, Render, ToTokens, TokenStream,
+    ATTR_NAME, BASE, OPTION, PRIVATE, RESULT,
 };
 use ::syn::parse::Parse;
 use quote::{quote, TokenStreamExt};
 
+struct Options {
+    parse_option: bool,
+    lenient: bool,
+    value_syntax: Option<Vec<ValueSyntax>>,
+}
+
+impl AttributeOptions for Options {
+    fn from_iter(_: Span, attributes: impl IntoIterator<Item = Attribute>) -> syn::Result<Self> {
+        let mut parse_option = None;
+        let mut lenient = None;
+        let mut value_syntax = None;
+
+        for attr in attributes {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("parse_option") {
+                    decode_attr_options_field(&mut parse_option, &meta.path, meta.input)
+                } else if meta.path.is_ident("lenient") {
+                    decode_attr_options_field(&mut lenient, &meta.path, meta.input)
+                } else if meta.path.is_ident("value_syntax") {
+                    decode_attr_options_field(&mut value_syntax, &meta.path, meta.input)
+                } else if meta.path.is_ident("from_parse") {
+                    // Belongs to `#[derive(ParseOption)]`
+                    decode_attr_options_field(&mut None::<bool>, &meta.path, meta.input)
+                } else {
+                    Err(meta.error(format_args!(
+                        "Unrecognised attribute: `{}`",
+                        meta.path.to_token_stream()
+                    )))
+                }
+            })?;
+        }
+
+        Ok(Self {
+            parse_option: parse_option.unwrap_or(false),
+            lenient: lenient.unwrap_or(false),
+            value_syntax: super::check_value_syntax(value_syntax)?,
+        })
+    }
+}
+
 impl Render for AttrOptionsDerive {
     const TRAIT_NAME: &'static str = "AttributeOptions";
 
@@ -46,6 +90,8 @@ pub struct AttrOptionsDerive {
     ident: Ident,
     generics: Generics,
     fields: Fields,
+    parse_option: bool,
+    value_syntax: Option<Vec<ValueSyntax>>,
 }
 
 impl Parse for AttrOptionsDerive {
@@ -54,13 +100,22 @@ impl Parse for AttrOptionsDerive {
             ident,
             generics,
             data,
+            attrs,
             ..
         } = input.parse()?;
 
+        let opts = Options::from_iter_named(ATTR_NAME, Span::call_site(), attrs)?;
+        let mut fields = Fields::try_from(data)?;
+        if opts.lenient {
+            fields.set_lenient();
+        }
+
         Ok(Self {
             ident,
             generics,
-            fields: data.try_into()?,
+            fields,
+            parse_option: opts.parse_option,
+            value_syntax: opts.value_syntax,
         })
     }
 }
@@ -71,6 +126,64 @@ impl ToTokens for AttrOptionsDerive {
     }
 
     fn to_token_stream(&self) -> TokenStream {
+        let mut tokens = self.render_attribute_options();
+        if self.parse_option {
+            self.render_parse_option(&mut tokens);
+        }
+
+        tokens
+    }
+}
+
+impl AttrOptionsDerive {
+    /// Implement `ParseOption`, `Parse` & `FromExpr` on top of the `AttributeOptions` impl
+    fn render_parse_option(&self, tokens: &mut TokenStream) {
+        tokens.extend(super::impl_generics(
+            &self.generics,
+            &self.ident,
+            "ParseOption",
+        ));
+        tokens.append(Group::new(
+            Delimiter::Brace,
+            quote! {
+                #[inline]
+                fn from_stream(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+                    #PRIVATE::decode_parse_option_from_attr_options(input)
+                }
+            },
+        ));
+
+        tokens.extend(super::impl_generics(
+            &self.generics,
+            &self.ident,
+            "FromExpr",
+        ));
+        tokens.append(Group::new(
+            Delimiter::Brace,
+            quote! {
+                #[inline]
+                fn from_expr(expr: ::syn::Expr) -> ::syn::Result<Self> {
+                    #PRIVATE::decode_attr_options_from_expr(expr)
+                }
+            },
+        ));
+
+        GenericImpl::new(&self.generics)
+            .with_trait(ModulePrefix::new(["syn", "parse", "Parse"]))
+            .with_target(&self.ident)
+            .to_tokens(tokens);
+        tokens.append(Group::new(
+            Delimiter::Brace,
+            quote! {
+                #[inline]
+                fn parse(input: ::syn::parse::ParseStream) -> ::syn::Result<Self> {
+                    <Self as #BASE::AttributeOptions>::from_stream(input)
+                }
+            },
+        ));
+    }
+
+    fn render_attribute_options(&self) -> TokenStream {
         let fields = match self.named_fields() {
             Ok(fields) => fields,
             Err(delim) => return self.render_empty(delim),
@@ -78,7 +191,7 @@ impl ToTokens for AttrOptionsDerive {
 
         let mut tokens = self.impl_generics();
 
-        let span_arg_name = if fields.iter().any(move |f| f.opts.omit_default()) {
+        let span_arg_name = if fields.iter().any(move |f| f.opts.required()) {
             Ident::create("attributes_span")
         } else {
             Ident::create("_")
@@ -87,44 +200,103 @@ impl ToTokens for AttrOptionsDerive {
         let fn_body = Group::new(Delimiter::Brace, {
             let indexed_fields = super::indexed_fields(fields);
             let nones = super::nones(fields);
+            let presence = super::presence(fields);
 
-            let matches = indexed_fields.clone()
-                .map(move |(option_var_name, field)| {
-                    let mut stream = field.resolved_label().into_token_stream();
-                    <Token![=>]>::default().to_tokens(&mut stream);
+            let accepted = self.value_syntax.as_deref().map(super::value_syntax_slice);
+            let matches = super::option_fields(fields).map(move |(option_var_name, field)| {
+                let mut stream = field.resolved_label().into_token_stream();
+                <Token![=>]>::default().to_tokens(&mut stream);
 
-                    stream.append(Group::new(
-                        Delimiter::Brace,
-                        quote! { #PRIVATE::decode_attr_options_field(&mut #option_var_name, ident, meta.input) },
-                    ));
+                let decode = field.decode_fn("decode_attr_options_field");
+                stream.append(Group::new(
+                    Delimiter::Brace,
+                    if let Some(ref accepted) = accepted {
+                        quote! {
+                            #PRIVATE::with_value_syntax(meta.input, #accepted, |input| {
+                                #PRIVATE::#decode(&mut #option_var_name, ident, input)
+                            })
+                        }
+                    } else {
+                        quote! { #PRIVATE::#decode(&mut #option_var_name, ident, meta.input) }
+                    },
+                ));
 
-                    stream
-                });
+                stream
+            });
 
             let unwraps = super::unwraps(indexed_fields, &span_arg_name);
 
-            quote! {
-                #nones
+            let match_keyed = quote! {
+                let ident = #PRIVATE::get_attr_ident(&meta.path)?;
 
-                for attr in attributes {
-                    attr.parse_nested_meta(|meta| {
-                        let ident = #PRIVATE::get_attr_ident(&meta.path)?;
+                match ::std::string::ToString::to_string(&::syn::ext::IdentExt::unraw(ident)).as_str() {
+                    #(#matches)*
+                    other => #RESULT::Err(::syn::Error::new(::syn::spanned::Spanned::span(ident), ::std::format!("Unrecognised attribute: `{}`", other))),
+                }
+            };
 
-                        match ::std::string::ToString::to_string(ident).as_str() {
-                            #(#matches)*
-                            other => #RESULT::Err(::syn::Error::new(::syn::spanned::Spanned::span(ident), ::std::format!("Unrecognised attribute: `{}`", other))),
-                        }
+            let labels = super::positional_labels(fields);
+            let name_value =
+                super::option_fields(fields).find(move |(_, f)| f.opts.from_name_value);
+
+            let name_value = name_value.map(move |(option_var_name, field)| {
+                let decode = field.decode_fn("decode_attr_options_field");
+                quote! { #PRIVATE::#decode(&mut #option_var_name, &meta.path, input) }
+            });
+
+            let parse_sources = if let Some(labels) = labels {
+                let positional = super::positional_match(fields);
+                let name_value = name_value
+                    .unwrap_or_else(|| quote!(#RESULT::Err(#PRIVATE::expected_list(meta))));
+
+                quote! {
+                    #PRIVATE::parse_option_input(source, #labels, |option| match option {
+                        #PRIVATE::OptionInput::Positional(idx, input) => #positional,
+                        #PRIVATE::OptionInput::Keyed(meta) => { #match_keyed }
+                        #PRIVATE::OptionInput::NameValue(meta, input) => #name_value,
                     })?;
                 }
+            } else if let Some(name_value) = name_value {
+                quote! {
+                    #PRIVATE::parse_keyed_input(source, |option| match option {
+                        #PRIVATE::KeyedInput::Keyed(meta) => { #match_keyed }
+                        #PRIVATE::KeyedInput::NameValue(meta, input) => #name_value,
+                    })?;
+                }
+            } else {
+                quote! {
+                    #PRIVATE::NestedMetaSource::parse_nested_meta(source, |meta| { #match_keyed })?;
+                }
+            };
+
+            let take_passed_on = take_passed_on(fields);
+
+            quote! {
+                #nones
+
+                for source in sources {
+                    #take_passed_on
+                    #parse_sources
+                }
 
+                #presence
                 #RESULT::Ok(Self #unwraps )
             }
         });
 
         // Struct body
         tokens.append(Group::new(Delimiter::Brace, {
+            let passed_on = passed_on_fns(fields);
+
             let mut signature = quote! {
-                fn from_iter(#span_arg_name: ::proc_macro2::Span, attributes: impl ::core::iter::IntoIterator<Item = ::syn::Attribute>) -> ::syn::Result<Self>
+                #passed_on
+
+                #[inline]
+                fn from_iter(span: ::proc_macro2::Span, attributes: impl ::core::iter::IntoIterator<Item = ::syn::Attribute>) -> ::syn::Result<Self> {
+                    Self::__from_nested_meta_sources(span, attributes)
+                }
+
+                fn __from_nested_meta_sources<S: #PRIVATE::NestedMetaSource>(#span_arg_name: ::proc_macro2::Span, sources: impl ::core::iter::IntoIterator<Item = S>) -> ::syn::Result<Self>
             };
             signature.append(fn_body);
             signature
@@ -133,3 +305,55 @@ impl ToTokens for AttrOptionsDerive {
         tokens
     }
 }
+
+/// Move doc comments & forwarded attributes into their fields before they reach option parsing
+fn take_passed_on(fields: &[super::Field]) -> TokenStream {
+    let mut out = TokenStream::new();
+
+    if let Some(docs_var_name) = super::docs_field(fields) {
+        out.extend(quote! {
+            let #OPTION::Some(source) = #PRIVATE::NestedMetaSource::take_docs(source, &mut #docs_var_name) else {
+                continue;
+            };
+        });
+    }
+
+    if let Some((forward_var_name, _)) = super::forward_attrs_field(fields) {
+        out.extend(quote! {
+            let #OPTION::Some(source) = #PRIVATE::NestedMetaSource::take_forwarded(source, <Self as #BASE::AttributeOptions>::__forwards_attr, &mut #forward_var_name) else {
+                continue;
+            };
+        });
+    }
+
+    out
+}
+
+/// Tell `from_iter_named` & friends which non-helper attributes to pass on
+fn passed_on_fns(fields: &[super::Field]) -> TokenStream {
+    let mut out = TokenStream::new();
+
+    if super::docs_field(fields).is_some() {
+        out.extend(quote!(
+            #[inline]
+            fn __collects_docs() -> bool {
+                true
+            }
+        ));
+    }
+
+    if let Some((_, paths)) = super::forward_attrs_field(fields) {
+        let names = paths.iter().map(move |path| {
+            let segments = path.segments.iter().map(move |s| s.ident.to_string());
+            quote!(#BASE::AttrName::matches_path(&[#(#segments),*], path))
+        });
+
+        out.extend(quote! {
+            fn __forwards_attr(path: &::syn::Path) -> bool {
+                false #(|| #names)*
+            }
+        });
+    }
+
+    out
+}
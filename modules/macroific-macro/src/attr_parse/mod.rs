@@ -3,10 +3,12 @@ use std::fmt::Write;
 use proc_macro2::TokenStream;
 use proc_macro2::{Delimiter, Group, Ident, Punct};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::{parse_macro_input, Generics};
 
 pub use attr_options::AttrOptionsDerive;
+use macroific_attr_parse::{Spanned, ValueSyntax};
 use macroific_core::core_ext::{MacroificCoreIdentExt, MacroificCorePunctExt};
 use macroific_core::elements::module_prefix::{OPTION, RESULT};
 use macroific_core::elements::{GenericImpl, ModulePrefix};
@@ -60,13 +62,114 @@ trait Render {
     }
 }
 
+/// Validate a container-level `#[attr_opts(value_syntax(...))]`
+fn check_value_syntax(
+    value_syntax: Option<Spanned<Vec<ValueSyntax>>>,
+) -> syn::Result<Option<Vec<ValueSyntax>>> {
+    match value_syntax {
+        Some(syntaxes) if syntaxes.is_empty() => Err(syn::Error::new(
+            syntaxes.key_span(),
+            "At least one value syntax must be accepted",
+        )),
+        Some(syntaxes) => Ok(Some(syntaxes.into_inner())),
+        None => Ok(None),
+    }
+}
+
+/// Render the accepted syntaxes as a `&[ValueSyntax]`
+fn value_syntax_slice(syntaxes: &[ValueSyntax]) -> TokenStream {
+    let base = BASE;
+    let syntaxes = syntaxes
+        .iter()
+        .map(move |syntax| Ident::create(&format!("{:?}", syntax)));
+    quote! { &[#(#base::ValueSyntax::#syntaxes),*] }
+}
+
 fn nones(fields: &[Field]) -> TokenStream {
-    (0..fields.len())
-        .map(move |idx| {
-            let ident = field_ident_at(idx);
-            quote! { let mut #ident = #OPTION::None; }
-        })
-        .collect()
+    let mut out: TokenStream = option_fields(fields)
+        .map(move |(ident, _)| quote! { let mut #ident = #OPTION::None; })
+        .collect();
+
+    if let Some(docs_var_name) = docs_field(fields) {
+        out.extend(quote! { let mut #docs_var_name = #BASE::Docs::new(); });
+    }
+    if let Some((forward_var_name, _)) = forward_attrs_field(fields) {
+        out.extend(quote! { let mut #forward_var_name = ::std::vec::Vec::new(); });
+    }
+
+    out
+}
+
+/// Collect the labels of provided options into `#[attr_opts(presence)]` fields
+fn presence(fields: &[Field]) -> TokenStream {
+    let mut out = TokenStream::new();
+    let presence_fields = indexed_fields(fields).filter(move |(_, f)| f.opts.presence);
+
+    for (presence_var_name, _) in presence_fields {
+        out.extend(quote! { let mut #presence_var_name = #BASE::Presence::new(); });
+
+        for (option_var_name, field) in option_fields(fields) {
+            let label = field.resolved_label();
+            out.extend(quote! {
+                if #option_var_name.is_some() {
+                    #presence_var_name.insert(#label);
+                }
+            });
+        }
+    }
+
+    out
+}
+
+/// Labels of the option fields for telling keyed options apart from positional values, e.g.
+/// `&["path", "guard"]`. [`None`] if there are no `#[attr_opts(positional)]` fields.
+fn positional_labels(fields: &[Field]) -> Option<TokenStream> {
+    if !fields.iter().any(move |f| f.opts.positional) {
+        return None;
+    }
+
+    let labels = option_fields(fields).map(move |(_, field)| field.resolved_label());
+    Some(quote! { &[#(#labels),*] })
+}
+
+/// Match a positional value's `idx` to its field, decoding from `input`
+fn positional_match(fields: &[Field]) -> TokenStream {
+    let positional = option_fields(fields).filter(move |(_, f)| f.opts.positional);
+    let count = positional.clone().count();
+    let arms = positional
+        .enumerate()
+        .map(move |(idx, (option_var_name, field))| {
+            let decode = field.decode_fn("decode_positional_field");
+            quote! { #idx => #PRIVATE::#decode(&mut #option_var_name, input), }
+        });
+
+    let too_many = if count == 1 {
+        String::from("Too many positional arguments, expected 1")
+    } else {
+        format!("Too many positional arguments, expected at most {count}")
+    };
+
+    quote! {
+        match idx {
+            #(#arms)*
+            _ => #RESULT::Err(input.error(#too_many)),
+        }
+    }
+}
+
+/// The `#[attr_opts(docs)]` field, if any
+fn docs_field(fields: &[Field]) -> Option<Ident> {
+    indexed_fields(fields)
+        .find(move |(_, f)| f.opts.docs)
+        .map(move |(docs_var_name, _)| docs_var_name)
+}
+
+/// The `#[attr_opts(forward_attrs(...))]` field and its paths, if any
+fn forward_attrs_field(fields: &[Field]) -> Option<(Ident, &[syn::Path])> {
+    indexed_fields(fields).find_map(move |(forward_var_name, f)| {
+        let paths = f.opts.forward_attrs.as_deref()?;
+        Some((forward_var_name, paths))
+    })
 }
 
 fn unwraps<'a>(
@@ -77,28 +180,36 @@ fn unwraps<'a>(
         let mut out = field.ident.to_token_stream();
         out.append(Punct::new_joint(':'));
 
+        if !field.opts.is_option() {
+            out.append(option_var_name);
+            out.append(Punct::new_joint(','));
+            return out;
+        }
+
         match field.opts.default {
+            None if field.opts.positional => {
+                out.extend(missing_field(
+                    field,
+                    &option_var_name,
+                    span_arg_name,
+                    "Missing positional argument: ",
+                ));
+            }
             None | Some(DefaultOption::Implicit | DefaultOption::Explicit(true)) => {
                 out.extend(quote! { #option_var_name.unwrap_or_default() });
             }
             Some(DefaultOption::Explicit(false)) => {
-                let mut missing_field_err = String::from("Missing required attribute: ");
-                if let Some(ref rename) = field.opts.rename {
-                    write!(&mut missing_field_err, "{}", rename.token()).unwrap();
-                } else {
-                    write!(&mut missing_field_err, "{}", field.ident).unwrap();
-                }
-
-                out.extend(quote! { if let #OPTION::Some(v) = #option_var_name {
-                    v
-                } else {
-                    return #RESULT::Err(::syn::Error::new(#span_arg_name, #missing_field_err));
-                } });
+                out.extend(missing_field(
+                    field,
+                    &option_var_name,
+                    span_arg_name,
+                    "Missing required attribute: ",
+                ));
             }
             Some(DefaultOption::Path(ref path)) => {
                 out.extend(quote! { #option_var_name.unwrap_or_else(#path) });
             }
-        };
+        }
 
         out.append(Punct::new_joint(','));
 
@@ -108,9 +219,30 @@ fn unwraps<'a>(
     Group::new(Delimiter::Brace, body.collect())
 }
 
+/// Unwrap a required field, erroring with `msg` followed by its label if it wasn't provided
+fn missing_field(
+    field: &Field,
+    option_var_name: &Ident,
+    span_arg_name: &impl ToTokens,
+    msg: &str,
+) -> TokenStream {
+    let mut missing_field_err = String::from(msg);
+    if let Some(ref rename) = field.opts.rename {
+        write!(&mut missing_field_err, "{}", rename.token()).unwrap();
+    } else {
+        write!(&mut missing_field_err, "{}", field.ident.unraw()).unwrap();
+    }
+
+    quote! { if let #OPTION::Some(v) = #option_var_name {
+        v
+    } else {
+        return #RESULT::Err(::syn::Error::new(#span_arg_name, #missing_field_err));
+    } }
+}
+
 type IndexedFieldTuple<'a> = (Ident, &'a Field);
 
-fn indexed_fields(fields: &[Field]) -> impl Iterator<Item = IndexedFieldTuple> + Clone {
+fn indexed_fields(fields: &[Field]) -> impl Iterator<Item = IndexedFieldTuple<'_>> + Clone {
     fields.iter().enumerate().map(move |(idx, field)| {
         let option_var_name = field_ident_at(idx);
 
@@ -118,6 +250,11 @@ fn indexed_fields(fields: &[Field]) -> impl Iterator<Item = IndexedFieldTuple> +
     })
 }
 
+/// [`indexed_fields`] that get parsed from options
+fn option_fields(fields: &[Field]) -> impl Iterator<Item = IndexedFieldTuple<'_>> + Clone {
+    indexed_fields(fields).filter(move |(_, field)| field.opts.is_option())
+}
+
 fn empty_ending(delimiter: Option<Delimiter>) -> Option<Group> {
     delimiter.map(move |d| Group::new(d, TokenStream::new()))
 }
@@ -3,23 +3,46 @@ use macroific_attr_parse::__attr_parse_prelude::*;
 use macroific_attr_parse::__private::{decode_attr_options_field, get_attr_ident};
 use proc_macro2::Span;
 use quote::ToTokens;
-use syn::{Attribute, LitStr};
+use syn::{Attribute, LitStr, Path};
 
+#[allow(clippy::struct_excessive_bools)]
 pub struct FieldOpts {
     pub default: Option<DefaultOption>,
     pub rename: Option<LitStr>,
+    pub presence: bool,
+    pub lenient: bool,
+    pub positional: bool,
+    pub from_name_value: bool,
+    pub docs: bool,
+    pub forward_attrs: Option<Vec<Path>>,
 }
 
 impl FieldOpts {
-    pub fn omit_default(&self) -> bool {
-        matches!(self.default, Some(DefaultOption::Explicit(false)))
+    /// Whether the field gets parsed from an option, i.e. isn't `presence`, `docs` or
+    /// `forward_attrs`
+    pub fn is_option(&self) -> bool {
+        !self.presence && !self.docs && self.forward_attrs.is_none()
+    }
+
+    /// Whether the field errors when not provided
+    pub fn required(&self) -> bool {
+        match self.default {
+            None => self.positional,
+            Some(ref default) => matches!(*default, DefaultOption::Explicit(false)),
+        }
     }
 }
 
 impl AttributeOptions for FieldOpts {
-    fn from_iter(_: Span, attrs: impl IntoIterator<Item = Attribute>) -> syn::Result<Self> {
+    fn from_iter(span: Span, attrs: impl IntoIterator<Item = Attribute>) -> syn::Result<Self> {
         let mut default = None;
         let mut rename = None;
+        let mut presence = None;
+        let mut lenient = None;
+        let mut positional = None;
+        let mut from_name_value = None;
+        let mut docs = None;
+        let mut forward_attrs = None;
 
         for attr in attrs {
             attr.parse_nested_meta(|meta| {
@@ -28,6 +51,16 @@ impl AttributeOptions for FieldOpts {
                 match ident.to_string().as_str() {
                     "default" => decode_attr_options_field(&mut default, ident, meta.input),
                     "rename" => decode_attr_options_field(&mut rename, ident, meta.input),
+                    "presence" => decode_attr_options_field(&mut presence, ident, meta.input),
+                    "lenient" => decode_attr_options_field(&mut lenient, ident, meta.input),
+                    "positional" => decode_attr_options_field(&mut positional, ident, meta.input),
+                    "from_name_value" => {
+                        decode_attr_options_field(&mut from_name_value, ident, meta.input)
+                    }
+                    "docs" => decode_attr_options_field(&mut docs, ident, meta.input),
+                    "forward_attrs" => {
+                        decode_attr_options_field(&mut forward_attrs, ident, meta.input)
+                    }
                     other => Err(syn::Error::new_spanned(
                         ident,
                         format!("Unrecognised attribute: `{}`", other),
@@ -36,7 +69,41 @@ impl AttributeOptions for FieldOpts {
             })?;
         }
 
-        Ok(Self { default, rename })
+        let presence = presence.unwrap_or(false);
+        let lenient = lenient.unwrap_or(false);
+        let positional = positional.unwrap_or(false);
+        let from_name_value = from_name_value.unwrap_or(false);
+        let docs = docs.unwrap_or(false);
+
+        let customised =
+            default.is_some() || rename.is_some() || lenient || positional || from_name_value;
+        let mut special = [
+            ("presence", presence),
+            ("docs", docs),
+            ("forward_attrs", forward_attrs.is_some()),
+        ]
+        .into_iter()
+        .filter_map(move |(name, set)| set.then_some(name));
+
+        if let Some(name) = special.next() {
+            if customised || special.next().is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`{name}` can't be combined with other options"),
+                ));
+            }
+        }
+
+        Ok(Self {
+            default,
+            rename,
+            presence,
+            lenient,
+            positional,
+            from_name_value,
+            docs,
+            forward_attrs,
+        })
     }
 }
 
@@ -51,6 +118,21 @@ impl std::fmt::Debug for FieldOpts {
             debug.field("rename", &None::<()>);
         }
 
+        debug.field("presence", &self.presence);
+        debug.field("lenient", &self.lenient);
+        debug.field("positional", &self.positional);
+        debug.field("from_name_value", &self.from_name_value);
+        debug.field("docs", &self.docs);
+
+        if let Some(ref paths) = self.forward_attrs {
+            let paths = paths
+                .iter()
+                .map(move |path| path.to_token_stream().to_string());
+            debug.field("forward_attrs", &paths.collect::<Vec<_>>());
+        } else {
+            debug.field("forward_attrs", &None::<()>);
+        }
+
         debug.finish()
     }
 }
@@ -1,8 +1,11 @@
 use proc_macro2::{Delimiter, Literal};
+use quote::format_ident;
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 use syn::{Data, FieldsNamed};
 
 use macroific_attr_parse::__attr_parse_prelude::*;
+use macroific_core::core_ext::MacroificCoreIdentExt;
 
 use super::super::ATTR_NAME;
 use super::FieldOpts;
@@ -62,7 +65,55 @@ impl Fields {
             })
         });
 
-        Ok(Self::Named(iter.collect::<syn::Result<_>>()?))
+        let fields: Vec<Field> = iter.collect::<syn::Result<_>>()?;
+
+        // Optional positional fields can only be told apart from required ones by their position
+        let mut positional = fields.iter().filter(move |f| f.opts.positional);
+        if positional.by_ref().any(move |f| !f.opts.required()) {
+            if let Some(field) = positional.find(move |f| f.opts.required()) {
+                return Err(syn::Error::new_spanned(
+                    &field.ident,
+                    "Required positional fields must come before optional ones",
+                ));
+            }
+        }
+
+        if let Some(field) = fields.iter().filter(move |f| f.opts.from_name_value).nth(1) {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "Only one field can be `from_name_value`",
+            ));
+        }
+
+        if let Some(field) = fields.iter().filter(move |f| f.opts.docs).nth(1) {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "Only one field can be `docs`",
+            ));
+        }
+
+        let mut forward_attrs = fields
+            .iter()
+            .filter(move |f| f.opts.forward_attrs.is_some());
+        if let Some(field) = forward_attrs.nth(1) {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "Only one field can be `forward_attrs`",
+            ));
+        }
+
+        Ok(Self::Named(fields))
+    }
+}
+
+impl Fields {
+    /// Apply a container-level `#[attr_opts(lenient)]` to every parsed field
+    pub fn set_lenient(&mut self) {
+        if let Self::Named(ref mut fields) = *self {
+            for field in fields.iter_mut().filter(move |f| f.opts.is_option()) {
+                field.opts.lenient = true;
+            }
+        }
     }
 }
 
@@ -71,7 +122,16 @@ impl Field {
         if let Some(ref rename) = self.opts.rename {
             rename.token()
         } else {
-            Literal::string(&self.ident.to_string())
+            Literal::string(&self.ident.unraw().to_string())
+        }
+    }
+
+    /// Name of the `__private` function decoding this field, e.g. `decode_attr_options_field`
+    pub fn decode_fn(&self, base: &str) -> proc_macro2::Ident {
+        if self.opts.lenient {
+            format_ident!("{}_lenient", base)
+        } else {
+            proc_macro2::Ident::create(base)
         }
     }
 }
@@ -1,7 +1,7 @@
 use proc_macro2::Span;
 use syn::{Attribute, DeriveInput, Token};
 
-use macroific_attr_parse::AttributeOptions;
+use macroific_attr_parse::{AttributeOptions, ValueSyntax};
 use macroific_attr_parse::__private::decode_attr_options_field;
 use macroific_core::core_ext::MacroificCoreIdentExt;
 use macroific_core::elements::{GenericImpl, ModulePrefix};
@@ -15,16 +15,24 @@ use quote::{quote, TokenStreamExt};
 
 struct Options {
     from_parse: bool,
+    lenient: bool,
+    value_syntax: Option<Vec<ValueSyntax>>,
 }
 
 impl AttributeOptions for Options {
     fn from_iter(_: Span, attributes: impl IntoIterator<Item = Attribute>) -> syn::Result<Self> {
         let mut from_parse = None;
+        let mut lenient = None;
+        let mut value_syntax = None;
 
         for attr in attributes {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("from_parse") {
                     decode_attr_options_field(&mut from_parse, &meta.path, meta.input)
+                } else if meta.path.is_ident("lenient") {
+                    decode_attr_options_field(&mut lenient, &meta.path, meta.input)
+                } else if meta.path.is_ident("value_syntax") {
+                    decode_attr_options_field(&mut value_syntax, &meta.path, meta.input)
                 } else {
                     Ok(())
                 }
@@ -33,6 +41,8 @@ impl AttributeOptions for Options {
 
         Ok(Self {
             from_parse: from_parse.unwrap_or(false),
+            lenient: lenient.unwrap_or(false),
+            value_syntax: super::check_value_syntax(value_syntax)?,
         })
     }
 }
@@ -85,6 +95,7 @@ pub enum ParseOptionDerive {
 pub struct ParseOptionCommonData {
     ident: Ident,
     generics: Generics,
+    value_syntax: Option<Vec<ValueSyntax>>,
 }
 
 impl Parse for ParseOptionDerive {
@@ -98,12 +109,49 @@ impl Parse for ParseOptionDerive {
         } = input.parse()?;
 
         let opts = Options::from_iter_named(ATTR_NAME, Span::call_site(), attrs)?;
-        let common = ParseOptionCommonData { ident, generics };
+        if opts.from_parse && opts.value_syntax.is_some() {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`value_syntax` can't be combined with `from_parse`",
+            ));
+        }
+
+        let common = ParseOptionCommonData {
+            ident,
+            generics,
+            value_syntax: opts.value_syntax,
+        };
 
         Ok(if opts.from_parse {
             Self::FromParse(common)
         } else {
-            Self::Base(common, data.try_into()?)
+            let mut fields = Fields::try_from(data)?;
+            if opts.lenient {
+                fields.set_lenient();
+            }
+
+            if let Fields::Named(ref fields) = fields {
+                if let Some(field) = fields.iter().find(move |f| f.opts.from_name_value) {
+                    return Err(syn::Error::new_spanned(
+                        &field.ident,
+                        "`from_name_value` is only supported by `AttributeOptions`",
+                    ));
+                }
+                if let Some(field) = fields.iter().find(move |f| f.opts.docs) {
+                    return Err(syn::Error::new_spanned(
+                        &field.ident,
+                        "`docs` is only supported by `AttributeOptions`",
+                    ));
+                }
+                if let Some(field) = fields.iter().find(move |f| f.opts.forward_attrs.is_some()) {
+                    return Err(syn::Error::new_spanned(
+                        &field.ident,
+                        "`forward_attrs` is only supported by `AttributeOptions`",
+                    ));
+                }
+            }
+
+            Self::Base(common, fields)
         })
     }
 }
@@ -124,14 +172,46 @@ impl ParseOptionDerive {
             },
         ));
 
+        self.render_from_expr(
+            &mut tokens,
+            &quote! {
+                #PRIVATE::decode_from_expr_from_parse(expr)
+            },
+        );
+
         tokens
     }
 
+    fn render_from_expr(&self, tokens: &mut TokenStream, body: &TokenStream) {
+        GenericImpl::new(self.generics())
+            .with_trait(BASE.chain(Ident::create("FromExpr")))
+            .with_target(self.ident())
+            .to_tokens(tokens);
+
+        tokens.append(Group::new(
+            Delimiter::Brace,
+            quote! {
+                #[inline]
+                fn from_expr(expr: ::syn::Expr) -> ::syn::Result<Self> {
+                    #body
+                }
+            },
+        ));
+    }
+
     #[inline]
     fn to_tokens_base(&self) -> TokenStream {
+        let from_expr_body = quote! {
+            #PRIVATE::decode_parse_option_from_expr(expr)
+        };
+
         let fields = match self.named_fields() {
             Ok(fields) => fields,
-            Err(delim) => return self.render_empty(delim),
+            Err(delim) => {
+                let mut tokens = self.render_empty(delim);
+                self.render_from_expr(&mut tokens, &from_expr_body);
+                return tokens;
+            }
         };
 
         let mut tokens = self.impl_generics();
@@ -139,28 +219,44 @@ impl ParseOptionDerive {
         let fn_body = Group::new(Delimiter::Brace, {
             let indexed_fields = super::indexed_fields(fields);
 
-            let matches = indexed_fields.clone()
-                .map(move |(option_var_name, field)| {
-                    let mut stream = field.resolved_label().into_token_stream();
-                    <Token![=>]>::default().to_tokens(&mut stream);
+            let matches = super::option_fields(fields).map(move |(option_var_name, field)| {
+                let mut stream = field.resolved_label().into_token_stream();
+                <Token![=>]>::default().to_tokens(&mut stream);
 
-                    stream.append(Group::new(
-                        Delimiter::Brace,
-                        quote! { #PRIVATE::decode_parse_option_field(&mut #option_var_name, ident, value_source) },
-                    ));
+                let decode = field.decode_fn("decode_parse_option_field");
+                stream.append(Group::new(
+                    Delimiter::Brace,
+                    quote! { #PRIVATE::#decode(&mut #option_var_name, ident, value_source) },
+                ));
 
-                    stream
-                });
+                stream
+            });
 
             let mut out = super::nones(fields);
+            let value_syntax = self.as_ref().value_syntax.as_deref();
+            let iterate = if let Some(labels) = super::positional_labels(fields) {
+                let accepted = value_syntax.map_or_else(
+                    || quote! { #BASE::ValueSyntax::DEFAULT },
+                    super::value_syntax_slice,
+                );
+                let positional = super::positional_match(fields);
+                quote! {
+                    #PRIVATE::iterate_option_meta_positional(parse, #accepted, #labels, &mut |idx, input| #positional)?
+                }
+            } else if let Some(accepted) = value_syntax {
+                let accepted = super::value_syntax_slice(accepted);
+                quote! { #PRIVATE::iterate_option_meta_in(parse, #accepted)? }
+            } else {
+                quote! { #PRIVATE::iterate_option_meta(parse)? }
+            };
 
             out.append_all(quote! {
                 // Provided ident, but no value, then continued to provide the next ident
                 if !parse.peek(::syn::Token![,]) {
-                    for result in #PRIVATE::iterate_option_meta(parse)? {
+                    for result in #iterate {
                         let (ident, value_source) = result?;
 
-                        match ::std::string::ToString::to_string(&ident).as_str() {
+                        match ::std::string::ToString::to_string(&::syn::ext::IdentExt::unraw(&ident)).as_str() {
                             #(#matches)*
                             other => return #RESULT::Err(::syn::Error::new(::syn::spanned::Spanned::span(&ident), ::std::format!("Unrecognised attribute: `{}`", other))),
                         }?;
@@ -169,6 +265,8 @@ impl ParseOptionDerive {
                 }
             });
 
+            out.extend(super::presence(fields));
+
             RESULT.to_tokens(&mut out);
             <Token![::]>::default().to_tokens(&mut out);
             out.append(Ident::create("Ok"));
@@ -212,6 +310,8 @@ impl ParseOptionDerive {
             },
         ));
 
+        self.render_from_expr(&mut tokens, &from_expr_body);
+
         tokens
     }
 }
@@ -23,8 +23,24 @@ mod attr_parse;
 /// | `#[attr_opts(rename = "new_ident")]` | Use this ident when parsing instead of the struct field's name |
 /// | `#[attr_opts(default = some_module::default_fn)]` | Use this function for the default value |
 /// | `#[attr_opts(default = false)]` | Make this option required and error if it isn't provided |
+/// | `#[attr_opts(presence)]` | Don't parse this field; fill it with a `Presence` of the options that were explicitly provided instead |
+/// | `#[attr_opts(lenient)]` | Convert values given in another literal form, e.g. `"3"` for a number or `foo` for a string |
+/// | `#[attr_opts(positional)]` | Fill this field from the leading un-keyed values in field order, e.g. `"/users"` in `#[get("/users", guard = auth)]`. Required unless it has a `default`; required positional fields must come before optional ones. |
+/// | `#[attr_opts(from_name_value)]` | Fill this field from the value of a `#[my = value]` attribute. At most one field per struct; `AttributeOptions` only. |
+/// | `#[attr_opts(docs)]` | Don't parse this field; fill it with the `Docs` from the doc comments next to the attributes instead. At most one field per struct; `AttributeOptions` only. |
+/// | `#[attr_opts(forward_attrs(cfg, allow))]` | Don't parse this field; fill a `Vec<Attribute>` with copies of the listed attributes next to the helper attributes instead. At most one field per struct; `AttributeOptions` only. |
 ///
 /// The alternative syntax is fine too, `#[attr_opts(default(false))]`
+///
+/// A bare `#[my]` parses as if no options were provided.
+///
+/// Keys may be keywords: a field named `r#type` is provided as `type = ...` or `r#type = ...`.
+///
+/// | Container Options |  |
+/// | ----- | ----- |
+/// | `#[attr_opts(parse_option)]` | Also implement `ParseOption`, `FromExpr` & [`Parse`](::syn::parse::Parse) using the same parser so the struct behaves identically as a top-level attribute & as a nested option. Don't combine with `#[derive(ParseOption)]`. |
+/// | `#[attr_opts(lenient)]` | Make every field lenient |
+/// | `#[attr_opts(value_syntax(eq, paren, colon, bracket, brace))]` | Value syntaxes to accept: `key = v`, `key(v)`, `key: v`, `key[v]` and `key { v }` respectively. Defaults to `eq` & `paren`; other forms error. |
 #[cfg(feature = "attr_parse")]
 #[proc_macro_derive(AttributeOptions, attributes(attr_opts))]
 pub fn derive_attribute_options(input: BaseTokenStream) -> BaseTokenStream {
@@ -33,9 +49,14 @@ pub fn derive_attribute_options(input: BaseTokenStream) -> BaseTokenStream {
 
 /// Derive the `ParseOption` trait for a struct. Uses the same field options as [`AttributeOptions`].
 ///
+/// `FromExpr` gets implemented too, making the struct usable as a field of another `ParseOption`
+/// struct. It accepts struct literals: `key = MyStruct { opt1: val1, opt2 }`.
+///
 /// | Container Options |  |
 /// | ----- | ----- |
-/// | `#[attr_opts(from_parse)]` | Call [`Parse::parse`](::syn::parse::Parse::parse) to implement `ParseOption`. `Parse` will also get implemented if this option is omitted or `false` |
+/// | `#[attr_opts(from_parse)]` | Call [`Parse::parse`](::syn::parse::Parse::parse) to implement `ParseOption` & `FromExpr`. `Parse` will also get implemented if this option is omitted or `false` |
+/// | `#[attr_opts(lenient)]` | Make every field lenient |
+/// | `#[attr_opts(value_syntax(eq, paren, colon, bracket, brace))]` | Value syntaxes to accept: `key = v`, `key(v)`, `key: v`, `key[v]` and `key { v }` respectively. Defaults to `eq` & `paren`; other forms error. Can't be combined with `from_parse`. |
 #[cfg(feature = "attr_parse")]
 #[proc_macro_derive(ParseOption, attributes(attr_opts))]
 pub fn derive_parse_option(input: BaseTokenStream) -> BaseTokenStream {
@@ -86,6 +86,256 @@
 //! assert_eq!(err.to_string(), r#"Missing required attribute: "A""#);
 //! ```
 //!
+//! Whether an option was explicitly provided, as opposed to falling back to its default, can be
+//! tracked with a [`Presence`] field.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::{prelude::*, Presence};
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   flag: bool,
+//!   #[attr_opts(rename = "n")]
+//!   num: u8,
+//!   #[attr_opts(presence)]
+//!   provided: Presence,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! { #[foo_attr(flag = false)] }).unwrap();
+//! assert!(opts.provided.is_set("flag"));
+//! assert!(!opts.provided.is_set("n"));
+//! ```
+//!
+//! Use [`Flag`] for booleans that need to remember where they were enabled and [`TriState`] for
+//! booleans that need to tell an explicit `false` apart from an omitted option.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::{prelude::*, Flag, TriState};
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   skip: Flag,
+//!   inline: TriState,
+//!   copy: TriState,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! { #[foo_attr(skip, inline = false)] }).unwrap();
+//! assert!(opts.skip.is_set());
+//! assert_eq!(opts.inline.value(), Some(false));
+//! assert!(opts.copy.is_absent());
+//! ```
+//!
+//! [`Spanned`] keeps track of where an option came from for reporting errors after parsing.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::{prelude::*, Spanned};
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   rename: Option<Spanned<String>>,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! { #[foo_attr(rename = "bar")] }).unwrap();
+//! let rename = opts.rename.unwrap();
+//! assert_eq!(*rename, "bar");
+//!
+//! let err = syn::Error::new(rename.value_span(), "this `rename` collides with another field");
+//! ```
+//!
+//! Options that accept several shapes can use [`Either`]; nest it for more than two alternatives.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::{prelude::*, Either};
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   default: Option<Either<bool, Either<syn::Path, u32>>>,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! { #[foo_attr(default = some::func)] }).unwrap();
+//! assert!(matches!(opts.default, Some(Either::Right(Either::Left(_)))));
+//! ```
+//!
+//! Numeric options accept constant expressions: negation, parentheses and basic arithmetic,
+//! bitwise operators & shifts get evaluated at parse time.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   offset: i32,
+//!   size: usize,
+//!   mask: u8,
+//!   ratio: f64,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! {
+//!   #[foo_attr(offset = -1, size = 4 * 1024, mask = 1 << 3, ratio = (1.0 + 2.0) / 4.0)]
+//! }).unwrap();
+//!
+//! assert_eq!(opts.offset, -1);
+//! assert_eq!(opts.size, 4096);
+//! assert_eq!(opts.mask, 8);
+//! assert_eq!(opts.ratio, 0.75);
+//! ```
+//!
+//! `i128`, `u128`, the `NonZero*` types & [`Wrapping`](std::num::Wrapping) are supported too;
+//! `Wrapping` arithmetic wraps around instead of erroring. Floats accept integer literals and
+//! evaluate the whole expression as floats, so `1 / 2` is `0.5`.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! # use std::num::{NonZeroU32, Wrapping};
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   #[attr_opts(default = false)]
+//!   workers: NonZeroU32,
+//!   seed: Wrapping<u128>,
+//!   ratio: f64,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! {
+//!   #[foo_attr(workers = 4, seed = 0 - 1, ratio = 1 / 2)]
+//! }).unwrap();
+//! assert_eq!(opts.workers.get(), 4);
+//! assert_eq!(opts.seed, Wrapping(u128::MAX));
+//! assert_eq!(opts.ratio, 0.5);
+//! ```
+//!
+//! Lists can be provided as `key(a, b)`, `key = [a, b]` or `key = (a, b)` and parsed into
+//! [`Vec`], [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet),
+//! [`BTreeSet`](std::collections::BTreeSet), arrays or tuples of up to 12 elements.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! # use std::collections::BTreeSet;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   names: Vec<String>,
+//!   tags: BTreeSet<syn::Ident>,
+//!   rgb: [u8; 3],
+//!   range: (i32, i32),
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! {
+//!   #[foo_attr(names("a", "b"), tags = [foo, bar], rgb = [255, 0, 128], range = (-1, 1))]
+//! }).unwrap();
+//!
+//! assert_eq!(opts.names, ["a", "b"]);
+//! assert_eq!(opts.tags.len(), 2);
+//! assert_eq!(opts.rgb, [255, 0, 128]);
+//! assert_eq!(opts.range, (-1, 1));
+//! ```
+//!
+//! Free-form key/value options can be parsed into a [`HashMap`](std::collections::HashMap),
+//! [`BTreeMap`](std::collections::BTreeMap) or, to keep the order they were provided in,
+//! [`OrderedMap`]. Keys can be identifiers or string literals; see [`MapKey`] for supported key
+//! types.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! # use std::collections::{BTreeMap, HashMap};
+//! use macroific::attr_parse::{prelude::*, OrderedMap};
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   env: BTreeMap<String, String>,
+//!   headers: HashMap<String, u16>,
+//!   steps: OrderedMap<syn::Ident, u8>,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! {
+//!   #[foo_attr(env(RUST_LOG = "info", PORT = "80"), headers("x-a" = 1), steps(b = 1, a = 2))]
+//! }).unwrap();
+//!
+//! assert_eq!(opts.env["RUST_LOG"], "info");
+//! assert_eq!(opts.env["PORT"], "80");
+//! assert_eq!(opts.headers["x-a"], 1);
+//!
+//! let steps = opts.steps.iter().map(|(k, v)| (k.to_string(), *v)).collect::<Vec<_>>();
+//! assert_eq!(steps, [("b".to_owned(), 1), ("a".to_owned(), 2)]);
+//! ```
+//!
+//! Identifiers, types, paths & where predicates can also be provided as string literals, which
+//! is handy for syntax that doesn't parse in a name-value position. Wrap any other `Parse`
+//! type in [`FromStrLit`] to parse it from a string literal.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! # use quote::ToTokens;
+//! use macroific::attr_parse::{prelude::*, FromStrLit};
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   rename: Option<syn::Ident>,
+//!   ty: Option<syn::Type>,
+//!   bound: Option<FromStrLit<syn::WhereClause>>,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! {
+//!   #[foo_attr(rename = "type", ty = "Vec<u8>", bound = "where T: Clone, U: Default")]
+//! }).unwrap();
+//!
+//! assert_eq!(opts.rename.unwrap(), "type");
+//! assert_eq!(opts.ty.unwrap().to_token_stream().to_string(), "Vec < u8 >");
+//! assert_eq!(opts.bound.unwrap().predicates.len(), 2);
+//! ```
+//!
+//! Naming patterns such as `"get_{field}"` can be parsed as a [`Template`], which validates &
+//! renders its placeholders.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! # use proc_macro2::Span;
+//! use macroific::attr_parse::{prelude::*, Template};
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   getter: Option<Template>,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! { #[foo_attr(getter = "get_{field}")] }).unwrap();
+//! let getter = opts.getter.unwrap();
+//! getter.validate(&["field"]).unwrap();
+//!
+//! let ident = getter.render_ident(Span::call_site(), |_| Some("name")).unwrap();
+//! assert_eq!(ident, "get_name");
+//! ```
+//!
+//! Values can also be read while the macro runs: [`EnvValue`] accepts `key = env("VAR_NAME")`
+//! and [`FileContents`] accepts `key = file("path/relative/to/crate/root")`. Cargo doesn't track
+//! either, so see their docs on triggering rebuilds.
+//!
+//! Keywords work as keys too; raw identifier fields are matched without their `r#` prefix.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions)]
+//! struct MyOptions {
+//!   r#type: Option<syn::Type>,
+//!   r#move: bool,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote!(#[my(type = u8, move)])).unwrap();
+//! assert!(opts.r#type.is_some());
+//! assert!(opts.r#move);
+//! ```
+//!
+//! Values forwarded by `macro_rules!`, e.g. `#[my_opts(count = $count)]` with `$count:expr`,
+//! parse the same as if they had been written out directly.
+//!
 //! Full table on supported syntaxes for providing option values can be found
 //! on [`parse_bool_attr`](ext::ParseBufferExt::parse_bool_attr) and
 //! [`parse_valued_attr`](ext::ParseBufferExt::parse_valued_attr). See the
@@ -132,6 +382,401 @@
 //! assert_eq!(opts, expect);
 //! ```
 //!
+//! `ParseOption` structs can be nested within each other too. Struct literals can be used
+//! alongside the list syntax.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(ParseOption, Debug, Eq, PartialEq)]
+//! struct Server {
+//!   host: String,
+//!   port: u16,
+//!   tls: bool,
+//! }
+//!
+//! #[derive(ParseOption, Debug, Eq, PartialEq)]
+//! struct Config {
+//!   primary: Option<Server>,
+//!   fallback: Option<Server>,
+//! }
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct Options {
+//!   #[attr_opts(default = false)]
+//!   config: Config,
+//! }
+//!
+//! let opts = Options::from_attr(parse_quote! { #[my(config(
+//!   primary(host = "a", port = 1, tls),
+//!   fallback = Server { host: "b", port: 2 },
+//! ))] }).unwrap();
+//!
+//! let expect = Config {
+//!   primary: Some(Server { host: "a".into(), port: 1, tls: true }),
+//!   fallback: Some(Server { host: "b".into(), port: 2, tls: false }),
+//! };
+//!
+//! assert_eq!(opts.config, expect);
+//! ```
+//!
+//! A struct that needs to work both as a top-level attribute and as a nested option can derive
+//! `AttributeOptions` with the `parse_option` container option instead.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug, Eq, PartialEq)]
+//! #[attr_opts(parse_option)]
+//! struct Route {
+//!   #[attr_opts(default = false)]
+//!   path: String,
+//!   method: Option<syn::Ident>,
+//! }
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct Api {
+//!   route: Option<Route>,
+//! }
+//!
+//! let top_level = Route::from_attr(parse_quote! { #[route(path = "/", method = get)] }).unwrap();
+//! let nested = Api::from_attr(parse_quote! { #[api(route(path = "/", method = get))] })
+//!   .unwrap()
+//!   .route
+//!   .unwrap();
+//!
+//! assert_eq!(top_level, nested);
+//!
+//! let literal = Api::from_attr(parse_quote! { #[api(route = Route { path: "/", method: get })] })
+//!   .unwrap()
+//!   .route
+//!   .unwrap();
+//!
+//! assert_eq!(top_level, literal);
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Attribute macro arguments</summary>
+//!
+//! `#[proc_macro_attribute]` arguments can be parsed directly with
+//! [`from_args`](AttributeOptions::from_args); nested `Meta`s can be parsed with
+//! [`from_meta`](AttributeOptions::from_meta).
+//!
+//! ```
+//! # use proc_macro2::TokenStream;
+//! # use quote::quote;
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   #[attr_opts(default = false)]
+//!   path: String,
+//!   strict: bool,
+//! }
+//!
+//! // You'd normally get this as the first argument of your attribute macro
+//! let args: TokenStream = quote!(path = "/", strict);
+//! let opts = MyOptions::from_args(args).unwrap();
+//! assert_eq!(opts.path, "/");
+//! assert!(opts.strict);
+//!
+//! let meta: syn::Meta = parse_quote!(route(path = "/users"));
+//! assert_eq!(MyOptions::from_meta(&meta).unwrap().path, "/users");
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Lenient values</summary>
+//!
+//! Options are strict about their literal forms by default. `#[attr_opts(lenient)]`, on the
+//! container or on individual fields, converts between them instead: numbers accept strings,
+//! booleans accept `"true"`, `"false"`, `1` & `0`, strings accept any literal or a bare
+//! identifier and identifiers accept keywords. Leniency carries through wrappers such as
+//! sequences, maps, `Option` & `Either`.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! #[attr_opts(lenient)]
+//! struct MyOptions {
+//!   count: u8,
+//!   enabled: bool,
+//!   name: String,
+//! }
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct StrictOptions {
+//!   count: u8,
+//!   #[attr_opts(lenient)]
+//!   name: String,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote! {
+//!   #[my(count = "3", enabled = "true", name = foo)]
+//! }).unwrap();
+//! assert_eq!(opts.count, 3);
+//! assert!(opts.enabled);
+//! assert_eq!(opts.name, "foo");
+//!
+//! let opts = StrictOptions::from_attr(parse_quote!(#[my(name = 5)])).unwrap();
+//! assert_eq!(opts.name, "5");
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Value syntax</summary>
+//!
+//! Options accept `key = value` and `key(value)` by default. DSLs using `key: value`,
+//! `key[value]` or `key { value }` can opt into them with `#[attr_opts(value_syntax(...))]`;
+//! forms that aren't listed produce an error.
+//!
+//! ```
+//! # use quote::quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(ParseOption, Debug, Default)]
+//! #[attr_opts(value_syntax(colon, bracket))]
+//! struct Route {
+//!   path: String,
+//!   methods: Vec<String>,
+//! }
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! #[attr_opts(value_syntax(colon, brace))]
+//! struct MyOptions {
+//!   name: String,
+//!   route: Route,
+//! }
+//!
+//! let opts = MyOptions::from_args(quote! {
+//!   name: "users",
+//!   route { path: "/users", methods["GET", "POST"] }
+//! }).unwrap();
+//! assert_eq!(opts.name, "users");
+//! assert_eq!(opts.route.path, "/users");
+//! assert_eq!(opts.route.methods, ["GET", "POST"]);
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Positional values</summary>
+//!
+//! `#[attr_opts(positional)]` fields are filled in order from the leading values that aren't
+//! keyed options, e.g. `#[get("/users/{id}", guard = auth)]`. They're required unless they have a
+//! `default` and can still be provided by their key.
+//!
+//! A value is keyed when it's `ident = value`, or a field's label followed by a delimited value,
+//! `: value`, `,` or nothing at all. A bare identifier that shares a field's label is therefore
+//! read as that option; pass such positional values by their key instead.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(ParseOption, Debug, Default)]
+//! struct Limit {
+//!   #[attr_opts(positional)]
+//!   count: u32,
+//!   #[attr_opts(positional, default)]
+//!   per: Option<syn::Ident>,
+//! }
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct Route {
+//!   #[attr_opts(positional)]
+//!   path: String,
+//!   guard: Option<syn::Ident>,
+//!   limit: Option<Limit>,
+//! }
+//!
+//! let opts = Route::from_attr(parse_quote! {
+//!   #[get("/users/{id}", guard = auth, limit(10, minute))]
+//! }).unwrap();
+//! assert_eq!(opts.path, "/users/{id}");
+//! assert_eq!(opts.guard.unwrap(), "auth");
+//!
+//! let limit = opts.limit.unwrap();
+//! assert_eq!(limit.count, 10);
+//! assert_eq!(limit.per.unwrap(), "minute");
+//!
+//! let opts = Route::from_attr(parse_quote!(#[get(path = "/")])).unwrap();
+//! assert_eq!(opts.path, "/");
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Short attribute forms</summary>
+//!
+//! A bare `#[my]` parses as if no options were provided. `#[my = value]` goes into the field
+//! marked with `#[attr_opts(from_name_value)]`, if any.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   #[attr_opts(from_name_value)]
+//!   rename: Option<String>,
+//!   skip: bool,
+//! }
+//!
+//! let opts = MyOptions::from_attr(parse_quote!(#[my = "foo"])).unwrap();
+//! assert_eq!(opts.rename.unwrap(), "foo");
+//!
+//! let opts = MyOptions::from_attr(parse_quote!(#[my(rename = "foo", skip)])).unwrap();
+//! assert_eq!(opts.rename.unwrap(), "foo");
+//! assert!(opts.skip);
+//!
+//! let opts = MyOptions::from_attr(parse_quote!(#[my])).unwrap();
+//! assert!(opts.rename.is_none());
+//! assert!(!opts.skip);
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Namespaced attributes & dotted keys</summary>
+//!
+//! [`from_iter_path`](AttributeOptions::from_iter_path) accepts any [`AttrName`]: a `&str` or
+//! `String` for `#[my(...)]` and a `&[&str]`, array or `ModulePrefix` for tool-style attributes
+//! such as `#[myorg::serde(...)]`. `FieldWithOpts`, `FieldsWithOpts` & `DeriveInputWithOpts` have
+//! a matching `from_attr_path`.
+//!
+//! Derive macros receive `#[cfg_attr(...)]`s already expanded, so `from_iter_path` leaves them
+//! alone. Code that parses unexpanded source, e.g. a build script, can use `from_iter_named_cfg` to
+//! unwrap matching attributes whose predicate holds for the given
+//! [features & `cfg` options](CfgFeatures).
+//!
+//! Options with dotted or path keys, e.g. `server.port = 80` or `server::port = 80`, get passed on
+//! to the nested option as `server(port = 80)`. Several of them are combined into one.
+//!
+//! ```
+//! # use syn::{parse_quote, Attribute};
+//! # use proc_macro2::Span;
+//! use macroific::attr_parse::prelude::*;
+//! use macroific::attr_parse::CfgFeatures;
+//!
+//! #[derive(ParseOption, Debug, Default)]
+//! struct Server {
+//!   host: Option<String>,
+//!   port: u16,
+//!   tls: Tls,
+//! }
+//!
+//! #[derive(ParseOption, Debug, Default)]
+//! struct Tls {
+//!   enabled: bool,
+//! }
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   server: Server,
+//!   name: Option<String>,
+//! }
+//!
+//! let attrs: Vec<Attribute> = vec![
+//!   parse_quote!(#[serde(name = "ignored")]),
+//!   parse_quote!(#[myorg::serde(server.port = 80, name = "foo", server::host = "localhost", server.tls.enabled)]),
+//! ];
+//! let opts = MyOptions::from_iter_path(["myorg", "serde"], Span::call_site(), attrs).unwrap();
+//!
+//! assert_eq!(opts.name.unwrap(), "foo");
+//! assert_eq!(opts.server.port, 80);
+//! assert_eq!(opts.server.host.unwrap(), "localhost");
+//! assert!(opts.server.tls.enabled);
+//!
+//! let attrs: Vec<Attribute> = vec![parse_quote!(#[cfg_attr(feature = "dev", my(name = "dev"))])];
+//! let features = CfgFeatures::new(["dev"]);
+//! let opts = MyOptions::from_iter_named_cfg("my", &features, Span::call_site(), attrs).unwrap();
+//! assert_eq!(opts.name.unwrap(), "dev");
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Doc comments</summary>
+//!
+//! A [`Docs`] field marked with `#[attr_opts(docs)]` collects the doc comments passed in with the
+//! attributes, e.g. a field's `///` comments when parsing [`FieldWithOpts`]. Their common
+//! indentation is removed and the attributes themselves are left on the field. Other `doc`
+//! attributes, such as `#[doc(hidden)]`, are ignored.
+//!
+//! ```
+//! # use syn::parse_quote;
+//! use macroific::attr_parse::prelude::*;
+//! use macroific::attr_parse::{Docs, FieldWithOpts};
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   #[attr_opts(docs)]
+//!   docs: Docs,
+//!   skip: bool,
+//! }
+//!
+//! let field: syn::Field = parse_quote! {
+//!   ///   The user's name.
+//!   #[my(skip)]
+//!   #[doc(hidden)]
+//!   ///
+//!   ///   Never empty.
+//!   name: String
+//! };
+//! let field = FieldWithOpts::<MyOptions>::from_attr_name(field, "my").unwrap();
+//!
+//! assert!(field.options.skip);
+//! assert_eq!(field.options.docs.text(), "The user's name.\n\nNever empty.");
+//! assert_eq!(field.options.docs.summary(), "The user's name.");
+//! assert_eq!(field.field.attrs.len(), 4);
+//!
+//! let attrs: Vec<syn::Attribute> = vec![parse_quote!(#[doc(hidden)]), parse_quote!(#[my(skip)])];
+//! let opts = MyOptions::from_iter_named("my", proc_macro2::Span::call_site(), attrs).unwrap();
+//! assert!(opts.docs.is_empty());
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Forwarding attributes</summary>
+//!
+//! A `Vec<Attribute>` field marked with `#[attr_opts(forward_attrs(...))]` gets copies of the
+//! listed attributes passed in with the helper attributes, e.g. to put a field's `#[cfg]`s &
+//! `#[allow]`s on generated code. [`FieldWithOpts::from_predicates`] collects attributes by a
+//! second predicate instead.
+//!
+//! ```
+//! # use syn::{parse_quote, Attribute};
+//! use macroific::attr_parse::prelude::*;
+//! use macroific::attr_parse::FieldWithOpts;
+//!
+//! #[derive(AttributeOptions, Debug)]
+//! struct MyOptions {
+//!   #[attr_opts(forward_attrs(cfg, allow, myorg::serde))]
+//!   forwarded: Vec<Attribute>,
+//!   skip: bool,
+//! }
+//!
+//! let field: syn::Field = parse_quote! {
+//!   #[cfg(feature = "std")]
+//!   #[my(skip)]
+//!   #[allow(dead_code)]
+//!   #[myorg::serde(rename = "x")]
+//!   #[serde(rename = "y")]
+//!   name: String
+//! };
+//! let field = FieldWithOpts::<MyOptions>::from_attr_name(field, "my").unwrap();
+//!
+//! assert!(field.options.skip);
+//! let forwarded = field.options.forwarded.iter()
+//!   .map(|attr| quote::ToTokens::to_token_stream(&attr.meta).to_string())
+//!   .collect::<Vec<_>>();
+//! assert_eq!(forwarded, ["cfg (feature = \"std\")", "allow (dead_code)", "myorg :: serde (rename = \"x\")"]);
+//! assert_eq!(field.field.attrs.len(), 4);
+//! ```
+//!
 //! </details>
 //!
 //! <details><summary>Enum option</summary>
@@ -13,7 +13,7 @@
 //! | `full` | Enable `syn/full`. If `attr_parse` is enabled, it'll implement the traits for types that require `syn/full`. |
 //! | `attributed` | Enable [`elements::Attributed`]. |
 //! | `generic-impl` | Enable [`elements::GenericImpl`]. |
-//! | `module-prefix` | Enable [`elements::ModulePrefix`]. |
+//! | `module-prefix` | Enable [`elements::ModulePrefix`]. If `attr_parse` is enabled, it can be used as an attribute name to match. |
 
 #![deny(clippy::correctness, clippy::suspicious)]
 #![warn(clippy::complexity, clippy::perf, clippy::style, clippy::pedantic)]